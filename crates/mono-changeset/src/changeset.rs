@@ -25,9 +25,16 @@
 
 //! Changeset.
 
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::result;
+
 use mono_project::version::Increment;
 use mono_project::{Manifest, Workspace};
 use mono_repository::commit::trim_trailers;
+use mono_repository::{Forge, Id, Repository};
 
 pub mod change;
 pub mod changelog;
@@ -36,11 +43,11 @@ mod error;
 pub mod revision;
 pub mod scopes;
 
-use change::Change;
-use config::Config;
+use change::{Change, Kind};
+use config::{Config, Scope, Strictness};
 pub use error::{Error, Result};
 use revision::Revision;
-use scopes::Scopes;
+use scopes::{Builder, Scopes};
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -60,13 +67,21 @@ pub struct Changeset<'a> {
     revisions: Vec<Revision<'a>>,
     /// Version increments.
     increments: Vec<Option<Increment>>,
+    /// Number of package scopes, which are indexed first.
+    packages: usize,
+    /// Handling of changes referencing unknown scopes.
+    strict_scopes: Strictness,
+    /// Unknown scopes referenced by changes.
+    unknown_scopes: BTreeSet<String>,
+    /// Forge for linking commits and issues.
+    forge: Option<Forge>,
 }
 
 // ----------------------------------------------------------------------------
 // Implementations
 // ----------------------------------------------------------------------------
 
-impl Changeset<'_> {
+impl<'a> Changeset<'a> {
     /// Creates a changeset.
     ///
     /// # Errors
@@ -92,25 +107,110 @@ impl Changeset<'_> {
     where
         T: Manifest,
     {
-        let mut builder = Scopes::builder();
-        for (path, name) in workspace.packages() {
-            builder.add(path.join("**"), name)?;
-        }
+        let mut builder = Builder::from_workspace(workspace)?;
 
-        // Append additional scopes from configuration
-        for (name, path) in &config.scopes {
-            builder.add(path, name)?;
+        // Collect additional scopes from configuration, where scopes without
+        // a path are virtual, as they're only referenced by name, and scopes
+        // with a pattern are matched through regular expressions
+        let mut other = Scopes::builder();
+        for (name, scope) in &config.scopes {
+            match scope {
+                Scope::Path(path) if path.as_os_str().is_empty() => {
+                    other.add_virtual(name)?;
+                }
+                Scope::Path(path) => {
+                    other.add(path, name)?;
+                }
+                Scope::Pattern { pattern } => {
+                    other.add_regex(pattern, name)?;
+                }
+            }
         }
 
+        // Merge additional scopes, skipping paths of workspace packages
+        builder.merge(other)?;
+
         // Create scope set and version increments
         let scopes = builder.build()?;
         Ok(Self {
             increments: vec![None; scopes.len()],
+            packages: workspace.packages_count(),
             scopes,
             revisions: Vec::new(),
+            strict_scopes: config.strict_scopes,
+            unknown_scopes: BTreeSet::new(),
+            forge: None,
         })
     }
 
+    /// Creates a changeset from the commits in the given range.
+    ///
+    /// This method creates the scope set as [`Changeset::new`] does, but adds
+    /// all commits after `from` up to `to`, or `HEAD` if not given, instead of
+    /// the commits since the last version, e.g., for branch-based analysis.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Scopes`] if the scope set can't be built,
+    /// and [`Error::Repository`] if the commits can't be retrieved.
+    pub fn from_commit_range<T>(
+        workspace: &Workspace<T>, repository: &'a Repository, from: Id,
+        to: Option<Id>,
+    ) -> Result<Self>
+    where
+        T: Manifest,
+    {
+        let config = Config::default();
+        Self::from_commit_range_with_config(
+            workspace, &config, repository, from, to,
+        )
+    }
+
+    /// Creates a changeset from the commits in the given range, with the given
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Scopes`] if the scope set can't be built,
+    /// and [`Error::Repository`] if the commits can't be retrieved.
+    pub fn from_commit_range_with_config<T>(
+        workspace: &Workspace<T>, config: &Config, repository: &'a Repository,
+        from: Id, to: Option<Id>,
+    ) -> Result<Self>
+    where
+        T: Manifest,
+    {
+        let mut changeset = Self::with_config(workspace, config)?;
+        changeset.add_range(repository, from, to)?;
+        Ok(changeset)
+    }
+
+    /// Limits the changeset to the given number of most recent revisions.
+    ///
+    /// This is useful for generating changelogs of only recent changes, e.g.,
+    /// in CI, where older changes are irrelevant. Version increments are then
+    /// recomputed from the remaining revisions, which means that truncated
+    /// changesets may miss changes of scopes only affected by older revisions.
+    #[must_use]
+    pub fn with_window(mut self, n: usize) -> Self {
+        self.revisions.truncate(n);
+
+        // Recompute version increments from the remaining revisions
+        self.increments.fill(None);
+        for revision in &self.revisions {
+            revision.update(&mut self.increments);
+        }
+
+        // Return changeset
+        self
+    }
+
+    /// Sets the forge, which is used to link commits and issues in changelogs.
+    #[inline]
+    pub fn set_forge(&mut self, forge: Option<Forge>) {
+        self.forge = forge;
+    }
+
     /// Returns the summary.
     ///
     /// The summary is given by the commit's body of the latest revision in the
@@ -134,6 +234,168 @@ impl Changeset<'_> {
             .then_some(summary)
             .ok_or(Error::Summary)
     }
+
+    /// Returns the highest version increment across all package scopes.
+    ///
+    /// Increments of virtual scopes and scopes added through configuration
+    /// are ignored, as they don't denote changes to any package.
+    #[must_use]
+    pub fn max_increment(&self) -> Option<Increment> {
+        let increments = &self.increments[..self.packages];
+        increments.iter().flatten().max().copied()
+    }
+
+    /// Returns whether the changeset contains changes requiring a release,
+    /// i.e., whether any package scope has a version increment.
+    #[inline]
+    #[must_use]
+    pub fn is_releasable(&self) -> bool {
+        self.max_increment().is_some()
+    }
+
+    /// Serializes the changeset to JSON.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Json`] if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Returns the revisions grouped by the names of affected scopes.
+    ///
+    /// Revisions affecting multiple scopes are included in each group, while
+    /// revisions affecting no scope at all are grouped under the empty name.
+    #[must_use]
+    pub fn grouped_by_scope(&self) -> BTreeMap<&str, Vec<&Revision<'_>>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for revision in &self.revisions {
+            if revision.scopes().is_empty() {
+                groups.entry("").or_default().push(revision);
+            }
+
+            // Add revision to the group of each affected scope
+            let iter = revision.scopes().iter();
+            for name in iter.filter_map(|&index| self.scopes.get_name(index)) {
+                groups.entry(name).or_default().push(revision);
+            }
+        }
+
+        // Return groups
+        groups
+    }
+
+    /// Creates an iterator over the revisions affecting the given scope.
+    ///
+    /// If the scope is not part of the scope set, the iterator is empty.
+    pub fn revisions_for_scope<N>(
+        &self, name: N,
+    ) -> impl Iterator<Item = &Revision<'_>>
+    where
+        N: AsRef<str>,
+    {
+        let index = self.scopes.get_by_name(name.as_ref());
+
+        // Return iterator over revisions affecting the scope
+        self.revisions.iter().filter(move |revision| {
+            index.is_some_and(|index| revision.scopes().contains(&index))
+        })
+    }
+
+    /// Returns the version increment for the scope with the given name.
+    ///
+    /// If the scope is not part of the scope set, or has no changes that
+    /// require a release, nothing is returned.
+    #[must_use]
+    pub fn increment_for_scope(&self, name: &str) -> Option<Increment> {
+        let index = self.scopes.get_by_name(name)?;
+        self.increments[index]
+    }
+
+    /// Returns the version increments of all scopes, keyed by scope name.
+    #[must_use]
+    pub fn increments_map(&self) -> BTreeMap<&str, Option<Increment>> {
+        let iter = self.scopes.names().zip(self.increments.iter().copied());
+        iter.collect()
+    }
+
+    /// Groups revisions by the kind of change.
+    ///
+    /// This is useful for statistics, e.g., for a release summary header like
+    /// "5 features, 3 fixes". Kinds without revisions are omitted.
+    #[must_use]
+    pub fn by_kind(&self) -> BTreeMap<Kind, Vec<&Revision<'_>>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for revision in &self.revisions {
+            let kind = revision.change().kind();
+            groups.entry(kind).or_default().push(revision);
+        }
+
+        // Return groups
+        groups
+    }
+
+    /// Creates an iterator over the revisions of the given kind.
+    pub fn revisions_of_kind(
+        &self, kind: Kind,
+    ) -> impl Iterator<Item = &Revision<'_>> {
+        self.revisions
+            .iter()
+            .filter(move |revision| revision.change().kind() == kind)
+    }
+
+    /// Creates an iterator over the revisions containing breaking changes.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Revision<'_>> {
+        self.revisions
+            .iter()
+            .filter(|revision| revision.change().is_breaking())
+    }
+
+    /// Returns the names of all contributors, i.e., authors and co-authors.
+    ///
+    /// Co-authors are given as `Name <email>`, so we extract the name, but
+    /// retain the entire value if it doesn't adhere to this format.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Repository`] if the trailers of a commit
+    /// can't be parsed.
+    pub fn contributors(&self) -> Result<BTreeSet<String>> {
+        let mut contributors = BTreeSet::new();
+        for revision in &self.revisions {
+            contributors.insert(revision.author());
+            for co_author in revision.co_authors()? {
+                contributors.insert(name(&co_author).to_string());
+            }
+        }
+
+        // Return contributors
+        Ok(contributors)
+    }
+
+    /// Returns all unique paths touched by the revisions in the changeset.
+    ///
+    /// Paths are relative to the root of the repository, and for renames, both
+    /// the original and new path are included, as both were affected.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Repository`] if the deltas of a commit
+    /// can't be retrieved.
+    pub fn affected_paths(&self) -> Result<BTreeSet<PathBuf>> {
+        let mut paths = BTreeSet::new();
+        for revision in &self.revisions {
+            for delta in revision.commit().deltas()? {
+                if let Some(from) = delta.from_path() {
+                    paths.insert(from.to_path_buf());
+                }
+                paths.insert(delta.path().to_path_buf());
+            }
+        }
+
+        // Return affected paths
+        Ok(paths)
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -150,12 +412,24 @@ impl Changeset<'_> {
         &self.revisions
     }
 
+    /// Returns a reference to the forge.
+    #[inline]
+    pub fn forge(&self) -> Option<&Forge> {
+        self.forge.as_ref()
+    }
+
     /// Returns a reference to the version increments.
     #[inline]
     pub fn increments(&self) -> &[Option<Increment>] {
         &self.increments
     }
 
+    /// Returns a reference to the unknown scopes referenced by changes.
+    #[inline]
+    pub fn unknown_scopes(&self) -> &BTreeSet<String> {
+        &self.unknown_scopes
+    }
+
     /// Returns the number of revisions.
     #[inline]
     pub fn len(&self) -> usize {
@@ -168,3 +442,393 @@ impl Changeset<'_> {
         self.revisions.is_empty()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Serialize for Changeset<'_> {
+    /// Serializes the changeset.
+    ///
+    /// Version increments are keyed by scope name, omitting unchanged scopes,
+    /// and revisions are serialized as owned revisions, as they borrow their
+    /// commits from the repository.
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let increments = self
+            .scopes
+            .names()
+            .zip(&self.increments)
+            .filter_map(|(name, &increment)| Some((name, increment?)))
+            .collect::<BTreeMap<_, _>>();
+
+        // Serialize increments and revisions
+        let mut state = serializer.serialize_struct("Changeset", 2)?;
+        state.serialize_field("increments", &increments)?;
+        state.serialize_field("revisions", &self.to_owned_revisions())?;
+        state.end()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Extracts the name from a `Name <email>` string, or returns it unchanged.
+fn name(value: &str) -> &str {
+    let value = value.trim();
+    match value
+        .strip_suffix('>')
+        .and_then(|rest| rest.rsplit_once('<'))
+    {
+        Some((name, _)) if !name.trim().is_empty() => name.trim(),
+        _ => value,
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use mono_repository::testing::Fixture;
+    use std::error::Error;
+
+    /// Creates a repository with a Cargo workspace of packages `a` and `b`, and
+    /// commits affecting `a`, then `a` and `b`, and finally no package at all.
+    fn fixture() -> Result<Fixture, Box<dyn Error>> {
+        let fixture = Fixture::new()?;
+        fixture
+            .write("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n")?;
+        for name in ["a", "b"] {
+            fixture.write(
+                format!("crates/{name}/Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n"),
+            )?;
+        }
+        fixture.commit("chore: initial commit")?;
+        fixture.write("crates/a/lib.rs", "")?;
+        fixture.commit("fix: fix bug in a")?;
+        fixture.write("crates/a/main.rs", "")?;
+        fixture.write("crates/b/lib.rs", "")?;
+        fixture.commit("feature: add feature to a and b")?;
+        fixture.write("README.md", "")?;
+        fixture.commit("docs: add readme")?;
+        Ok(fixture)
+    }
+
+    mod add {
+        use mono_project::version::Increment;
+        use mono_project::{Python, Workspace};
+        use mono_repository::testing::Fixture;
+        use std::error::Error;
+        use std::path::PathBuf;
+
+        use crate::changeset::config::{Config, Scope, Strictness};
+        use crate::changeset::Changeset;
+
+        #[test]
+        fn handles_nested_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[project]\nname = \"root\"\nversion = \"1.0.0\"\n\
+                 [tool.mono]\nmembers = [\"packages/*\"]\n",
+            )?;
+            fixture.write(
+                "packages/a/pyproject.toml",
+                "[project]\nname = \"a\"\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+            fixture.write("packages/a/a.py", "")?;
+            fixture.commit("fix: fix bug in a")?;
+
+            // Add latest commit, which must only affect the nested package
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.add(repo.find("HEAD")?)?;
+            let scopes = changeset.scopes();
+            let (a, root) =
+                (scopes.get_by_name("a"), scopes.get_by_name("root"));
+            let increments = changeset.increments();
+            assert_eq!(
+                increments[a.expect("invariant")],
+                Some(Increment::Patch)
+            );
+            assert_eq!(increments[root.expect("invariant")], None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_shared_paths() -> Result<(), Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[project]\nname = \"root\"\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+            fixture.write("proto/service.proto", "")?;
+            fixture.commit("feature: add service definition")?;
+
+            // Add latest commit, which must affect both scopes sharing a path
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut config = Config::default();
+            for name in ["service-a", "service-b"] {
+                let scope = Scope::Path(PathBuf::from("proto/**"));
+                config.scopes.insert(name.to_string(), scope);
+            }
+            let mut changeset = Changeset::with_config(&workspace, &config)?;
+            changeset.add(repo.find("HEAD")?)?;
+            let scopes = changeset.scopes();
+            let increments = changeset.increments();
+            for name in ["service-a", "service-b"] {
+                let index = scopes.get_by_name(name).expect("invariant");
+                assert_eq!(increments[index], Some(Increment::Minor));
+            }
+            let root = scopes.get_by_name("root").expect("invariant");
+            assert_eq!(increments[root], None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_unknown_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[project]\nname = \"a\"\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.commit("fix(b): fix bug in b")?;
+
+            // Add commit with unknown scope, which is recorded when warning
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let config = Config {
+                strict_scopes: Strictness::Warn,
+                ..Config::default()
+            };
+            let mut changeset = Changeset::with_config(&workspace, &config)?;
+            changeset.add(repo.find("HEAD")?)?;
+            assert!(changeset.unknown_scopes().contains("b"));
+
+            // Add commit with unknown scope, which is rejected when erroring
+            let config = Config {
+                strict_scopes: Strictness::Error,
+                ..Config::default()
+            };
+            let mut changeset = Changeset::with_config(&workspace, &config)?;
+            let res = changeset.add(repo.find("HEAD")?);
+            assert!(matches!(res, Err(crate::changeset::Error::Scope(_))));
+            Ok(())
+        }
+    }
+
+    mod grouped_by_scope {
+        use mono_project::{Cargo, Workspace};
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            for spec in ["HEAD", "HEAD~1", "HEAD~2"] {
+                changeset.add(repo.find(spec)?)?;
+            }
+
+            // Collect summaries of revisions in each group, which must include
+            // revisions affecting multiple scopes in each of them
+            let groups = changeset.grouped_by_scope();
+            let iter = groups.iter().map(|(name, revisions)| {
+                let iter = revisions.iter();
+                (*name, iter.map(|r| r.commit().summary()).collect())
+            });
+            assert_eq!(
+                iter.collect::<Vec<(_, Vec<_>)>>(),
+                [
+                    ("", vec!["docs: add readme"]),
+                    (
+                        "a",
+                        vec![
+                            "feature: add feature to a and b",
+                            "fix: fix bug in a"
+                        ]
+                    ),
+                    ("b", vec!["feature: add feature to a and b"]),
+                ]
+            );
+            Ok(())
+        }
+    }
+
+    mod is_releasable {
+        use mono_project::{Cargo, Workspace};
+        use std::error::Error;
+        use std::path::PathBuf;
+
+        use crate::changeset::config::{Config, Scope};
+        use crate::changeset::Changeset;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_virtual_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            fixture.write(".github/workflows/ci.yml", "")?;
+            fixture.commit("fix(ci): fix workflow")?;
+
+            // Add latest commit, which only affects the virtual scope, so no
+            // package has changed and nothing must be released
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut config = Config::default();
+            config
+                .scopes
+                .insert("ci".to_string(), Scope::Path(PathBuf::new()));
+            let mut changeset = Changeset::with_config(&workspace, &config)?;
+            changeset.add(repo.find("HEAD")?)?;
+            let index = changeset.scopes().get_by_name("ci");
+            let increments = changeset.increments();
+            assert!(increments[index.expect("invariant")].is_some());
+            assert!(!changeset.is_releasable());
+            Ok(())
+        }
+    }
+
+    mod max_increment {
+        use mono_project::version::Increment;
+        use mono_project::{Cargo, Workspace};
+        use std::error::Error;
+        use std::path::PathBuf;
+
+        use crate::changeset::config::{Config, Scope};
+        use crate::changeset::Changeset;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_virtual_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            fixture.write("docs/guide.md", "")?;
+            fixture.commit("feature(docs): add guide")?;
+
+            // Add fix in `a` and feature in virtual scope, which must not be
+            // considered, as it doesn't denote a change to any package
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut config = Config::default();
+            config
+                .scopes
+                .insert("docs".to_string(), Scope::Path(PathBuf::new()));
+            let mut changeset = Changeset::with_config(&workspace, &config)?;
+            for spec in ["HEAD", "HEAD~3"] {
+                changeset.add(repo.find(spec)?)?;
+            }
+            assert_eq!(changeset.max_increment(), Some(Increment::Patch));
+            Ok(())
+        }
+    }
+
+    mod name {
+        use crate::changeset::name;
+
+        #[test]
+        fn handles_name_and_email() {
+            assert_eq!(name("Alice <alice@example.com>"), "Alice");
+            assert_eq!(
+                name("Alice Smith  <alice@example.com> "),
+                "Alice Smith"
+            );
+        }
+
+        #[test]
+        fn handles_invalid_format() {
+            assert_eq!(name("Alice"), "Alice");
+            assert_eq!(name("<alice@example.com>"), "<alice@example.com>");
+        }
+    }
+
+    mod revisions_for_scope {
+        use mono_project::{Cargo, Workspace};
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            for spec in ["HEAD", "HEAD~1", "HEAD~2"] {
+                changeset.add(repo.find(spec)?)?;
+            }
+
+            // Collect summaries of revisions affecting the scope
+            let iter = changeset.revisions_for_scope("b");
+            assert_eq!(
+                iter.map(|r| r.commit().summary()).collect::<Vec<_>>(),
+                ["feature: add feature to a and b"]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_unknown_scope() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.add(repo.find("HEAD~1")?)?;
+            assert_eq!(changeset.revisions_for_scope("c").count(), 0);
+            Ok(())
+        }
+    }
+
+    mod with_window {
+        use mono_project::version::Increment;
+        use mono_project::{Cargo, Workspace};
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_increments() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Cargo>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            for spec in ["HEAD", "HEAD~1", "HEAD~2"] {
+                changeset.add(repo.find(spec)?)?;
+            }
+
+            // Limit changeset to the two most recent revisions, which must
+            // recompute increments, as the fix in `a` is now outside of it
+            let changeset = changeset.with_window(2);
+            assert_eq!(changeset.revisions().len(), 2);
+            assert_eq!(
+                changeset.increments_map().into_iter().collect::<Vec<_>>(),
+                [("a", Some(Increment::Minor)), ("b", Some(Increment::Minor))]
+            );
+
+            // Limit changeset to the most recent revision only, which doesn't
+            // affect any scope, so nothing is released
+            let changeset = changeset.with_window(1);
+            assert!(!changeset.is_releasable());
+            Ok(())
+        }
+    }
+}
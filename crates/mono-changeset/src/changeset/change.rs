@@ -25,8 +25,10 @@
 
 //! Change.
 
+use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::{self, Write};
+use std::iter;
 use std::str::FromStr;
 
 use mono_project::version::Increment;
@@ -42,16 +44,20 @@ pub use kind::Kind;
 // ----------------------------------------------------------------------------
 
 /// Change.
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Change {
     /// Change kind.
     kind: Kind,
+    /// Change scope.
+    scope: Option<String>,
     /// Change summary.
     summary: String,
     /// Change references.
     references: Vec<u32>,
     /// Change is breaking.
     is_breaking: bool,
+    /// Breaking change description.
+    breaking_description: Option<String>,
 }
 
 // ----------------------------------------------------------------------------
@@ -66,7 +72,8 @@ impl Change {
     /// ```
     /// # use std::error::Error;
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// use mono_changeset::{Change, Increment};
+    /// use mono_changeset::Change;
+    /// use mono_project::version::Increment;
     ///
     /// // Create increment from change
     /// let change: Change = "fix: summary".parse()?;
@@ -79,8 +86,10 @@ impl Change {
         let increment = match self.kind {
             Kind::Feature => Increment::Minor,
             Kind::Fix => Increment::Patch,
+            Kind::Security => Increment::Patch,
             Kind::Performance => Increment::Patch,
             Kind::Refactor => Increment::Patch,
+            Kind::Revert => Increment::Patch,
             _ => return None,
         };
 
@@ -92,6 +101,59 @@ impl Change {
             Some(increment)
         }
     }
+
+    /// Applies the footers of the given commit body to the change.
+    ///
+    /// Breaking changes can also be denoted with a `BREAKING CHANGE:` footer,
+    /// as defined in the conventional commits specification, which includes a
+    /// description of the breaking change. Tokens are matched ignoring case,
+    /// and `BREAKING-CHANGE:` is accepted as a synonym. Like git, we treat
+    /// lines starting with whitespace as continuation lines of the preceding
+    /// footer, and join them with single spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Change;
+    ///
+    /// // Create change and apply body with breaking change footer
+    /// let mut change: Change = "fix: summary".parse()?;
+    /// change.apply_body("BREAKING CHANGE: description");
+    /// assert!(change.is_breaking());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_body<B>(&mut self, body: B)
+    where
+        B: AsRef<str>,
+    {
+        let mut lines = body.as_ref().lines();
+        while let Some(line) = lines.next() {
+            let Some((token, description)) = line.split_once(':') else {
+                continue;
+            };
+
+            // Check for breaking change token, and extract description, which
+            // might be continued on the following lines
+            let token = token.to_ascii_uppercase();
+            if token == "BREAKING CHANGE" || token == "BREAKING-CHANGE" {
+                let iter = lines
+                    .take_while(|line| line.starts_with(char::is_whitespace));
+                let description = iter::once(description)
+                    .chain(iter)
+                    .map(str::trim)
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                self.is_breaking = true;
+                self.breaking_description =
+                    (!description.is_empty()).then_some(description);
+                break;
+            }
+        }
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -102,6 +164,12 @@ impl Change {
         self.kind
     }
 
+    /// Returns the change scope.
+    #[inline]
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
     /// Returns the change summary.
     #[inline]
     pub fn summary(&self) -> &str {
@@ -119,6 +187,12 @@ impl Change {
     pub fn is_breaking(&self) -> bool {
         self.is_breaking
     }
+
+    /// Returns the breaking change description.
+    #[inline]
+    pub fn breaking_description(&self) -> Option<&str> {
+        self.breaking_description.as_deref()
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -133,8 +207,9 @@ impl FromStr for Change {
     /// # Errors
     ///
     /// This methods return [`Error::Format`][], if the string does not adhere
-    /// to conventional commits format (without scopes), and [`Error::Kind`][],
-    /// if the string does not correspond to a valid [`Kind`] variant.
+    /// to conventional commits format, and [`Error::Kind`][], if the string
+    /// does not correspond to a valid [`Kind`] variant. Scopes are optional,
+    /// and can be given in parenthesis after the kind, e.g. `fix(mono): ..`.
     ///
     /// # Examples
     ///
@@ -153,15 +228,41 @@ impl FromStr for Change {
             return Err(Error::Format);
         };
 
+        // Ensure the separator is followed by exactly one space, as anything
+        // else is a violation of the conventional commits format
+        if summary.starts_with(char::is_whitespace) {
+            return Err(Error::Format);
+        }
+
         // Check if we have a breaking change, denoted by an exclamation mark
-        // at the end of the string, and extract and parse the change kind
-        let (kind, is_breaking) = match kind.split_once('!') {
-            Some((kind, _)) => (Kind::from_str(kind)?, true),
-            None => (Kind::from_str(kind)?, false),
+        // at the end of the string, which might also precede the scope
+        let (kind, is_breaking) = match kind.strip_suffix('!') {
+            Some(kind) => (kind, true),
+            None => (kind, false),
+        };
+
+        // Extract the optional scope, which must be wrapped in parenthesis
+        let (kind, scope) = match kind.split_once('(') {
+            Some((kind, scope)) => {
+                let scope = scope
+                    .strip_suffix(')')
+                    .filter(|scope| !scope.is_empty())
+                    .filter(|scope| !scope.contains(['(', ')', ' ']))
+                    .ok_or(Error::Format)?;
+                (kind, Some(scope.to_string()))
+            }
+            None => (kind, None),
+        };
+
+        // Parse the change kind, and check for an exclamation mark again, in
+        // case the breaking change is denoted before the scope
+        let (kind, is_breaking) = match kind.strip_suffix('!') {
+            Some(kind) => (Kind::from_str(kind)?, true),
+            None => (Kind::from_str(kind)?, is_breaking),
         };
 
-        // Ensure summary has no leading or trailing whitespace, as we aim to
-        // be as strict as possible with the conventional commits format
+        // Ensure summary has no trailing whitespace, as we aim to be as strict
+        // as possible with the conventional commits format
         if summary != summary.trim() {
             return Err(Error::Whitespace);
         }
@@ -193,9 +294,11 @@ impl FromStr for Change {
         let mut references = BTreeSet::new();
         Ok(Change {
             kind,
+            scope,
             summary: extract(summary, &mut references)?,
             references: Vec::from_iter(references),
             is_breaking,
+            breaking_description: None,
         })
     }
 }
@@ -206,6 +309,11 @@ impl fmt::Display for Change {
     /// Formats the change for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.kind.fmt(f)?;
+        if let Some(scope) = &self.scope {
+            f.write_char('(')?;
+            f.write_str(scope)?;
+            f.write_char(')')?;
+        }
         if self.is_breaking {
             f.write_char('!')?;
         }
@@ -306,6 +414,78 @@ fn extract(value: &str, references: &mut BTreeSet<u32>) -> Result<String> {
 #[cfg(test)]
 mod tests {
 
+    mod as_increment {
+        use mono_project::version::Increment;
+        use std::str::FromStr;
+
+        use crate::changeset::change::{Change, Result};
+
+        #[test]
+        fn handles_revert_and_security() -> Result {
+            for (format, increment) in [
+                ("revert: summary", Increment::Patch),
+                ("security: summary", Increment::Patch),
+                ("security!: summary", Increment::Major),
+            ] {
+                let change = Change::from_str(format)?;
+                assert_eq!(change.as_increment(), Some(increment));
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_ci() -> Result {
+            let change = Change::from_str("ci: summary")?;
+            assert_eq!(change.as_increment(), None);
+            Ok(())
+        }
+    }
+
+    mod apply_body {
+        use std::str::FromStr;
+
+        use crate::changeset::change::{Change, Result};
+
+        #[test]
+        fn handles_breaking_suffix() -> Result {
+            let mut change = Change::from_str("fix!: summary")?;
+            change.apply_body("Body\n\nSigned-off-by: Jane <jane@example.com>");
+            assert!(change.is_breaking());
+            assert_eq!(change.breaking_description(), None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_breaking_footer() -> Result {
+            for body in [
+                "Body\n\nBREAKING CHANGE: description",
+                "Body\n\nbreaking-change: description",
+            ] {
+                let mut change = Change::from_str("fix: summary")?;
+                change.apply_body(body);
+                assert!(change.is_breaking());
+                assert_eq!(change.breaking_description(), Some("description"));
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn handles_breaking_footer_continuation() -> Result {
+            let mut change = Change::from_str("fix: summary")?;
+            change.apply_body(
+                "Body\n\n\
+                 BREAKING CHANGE: first\n  second\n\tthird\n\
+                 Signed-off-by: Jane <jane@example.com>",
+            );
+            assert!(change.is_breaking());
+            assert_eq!(
+                change.breaking_description(),
+                Some("first second third")
+            );
+            Ok(())
+        }
+    }
+
     #[allow(clippy::bool_assert_comparison)]
     mod from_str {
         use std::str::FromStr;
@@ -330,6 +510,41 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn handles_scope() -> Result {
+            let change = Change::from_str("fix(mono): summary")?;
+            assert_eq!(change.kind, Kind::Fix);
+            assert_eq!(change.scope.as_deref(), Some("mono"));
+            assert_eq!(change.is_breaking, false);
+            assert_eq!(change.summary, "summary");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_breaking_scope() -> Result {
+            for format in ["fix(mono)!: summary", "fix!(mono): summary"] {
+                let change = Change::from_str(format)?;
+                assert_eq!(change.kind, Kind::Fix);
+                assert_eq!(change.scope.as_deref(), Some("mono"));
+                assert_eq!(change.is_breaking, true);
+                assert_eq!(change.to_string(), "fix(mono)!: summary");
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_scope() {
+            for format in [
+                "fix(): summary",
+                "fix(mono: summary",
+                "fix(mo no): summary",
+                "fix((mono)): summary",
+            ] {
+                let res = Change::from_str(format);
+                assert!(matches!(res, Err(Error::Format)));
+            }
+        }
+
         #[test]
         fn errors_on_invalid_format() {
             for format in [
@@ -25,6 +25,7 @@
 
 //! Change kind.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
@@ -35,18 +36,27 @@ use super::error::{Error, Result};
 // ----------------------------------------------------------------------------
 
 /// Change kind.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum Kind {
     /// Feature.
     Feature,
     /// Bugfix.
     Fix,
+    /// Security fix.
+    Security,
     /// Performance improvement.
     Performance,
     /// Refactoring.
     Refactor,
+    /// Revert.
+    Revert,
     /// Build.
     Build,
+    /// Continuous integration.
+    Ci,
     /// Documentation.
     Docs,
     /// Formatting.
@@ -63,17 +73,49 @@ pub enum Kind {
 
 impl Kind {
     /// Valid change kind values.
-    pub const VALUES: [Kind; 9] = [
+    pub const VALUES: [Kind; 12] = [
         Kind::Feature,
         Kind::Fix,
+        Kind::Security,
         Kind::Performance,
         Kind::Refactor,
+        Kind::Revert,
         Kind::Build,
+        Kind::Ci,
         Kind::Docs,
         Kind::Style,
         Kind::Test,
         Kind::Chore,
     ];
+
+    /// Attempts to create a change kind from a string, ignoring case.
+    ///
+    /// Unlike [`Kind::from_str`], which only accepts lowercase values, as
+    /// mandated when validating commit messages, this method also accepts
+    /// values like `Fix` or `FEATURE`, which is useful when processing commit
+    /// messages from external systems, e.g., pull request titles.
+    ///
+    /// # Errors
+    ///
+    /// This methods return [`Error::Kind`], if the string does not correspond
+    /// to a valid [`Kind`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::change::Kind;
+    ///
+    /// // Create change kind from string, ignoring case
+    /// let kind = Kind::from_str_lenient("Fix")?;
+    /// assert_eq!(kind, Kind::Fix);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_str_lenient(value: &str) -> Result<Self> {
+        Kind::from_str(&value.to_lowercase())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -106,9 +148,12 @@ impl FromStr for Kind {
         match value {
             "feature" => Ok(Kind::Feature),
             "fix" => Ok(Kind::Fix),
+            "security" => Ok(Kind::Security),
             "performance" => Ok(Kind::Performance),
             "refactor" => Ok(Kind::Refactor),
+            "revert" => Ok(Kind::Revert),
             "build" => Ok(Kind::Build),
+            "ci" => Ok(Kind::Ci),
             "docs" => Ok(Kind::Docs),
             "style" => Ok(Kind::Style),
             "test" => Ok(Kind::Test),
@@ -126,9 +171,12 @@ impl fmt::Display for Kind {
         match self {
             Kind::Feature => f.write_str("feature"),
             Kind::Fix => f.write_str("fix"),
+            Kind::Security => f.write_str("security"),
             Kind::Performance => f.write_str("performance"),
             Kind::Refactor => f.write_str("refactor"),
+            Kind::Revert => f.write_str("revert"),
             Kind::Build => f.write_str("build"),
+            Kind::Ci => f.write_str("ci"),
             Kind::Docs => f.write_str("docs"),
             Kind::Style => f.write_str("style"),
             Kind::Test => f.write_str("test"),
@@ -154,9 +202,12 @@ mod tests {
             for (value, kind) in [
                 ("feature", Kind::Feature),
                 ("fix", Kind::Fix),
+                ("security", Kind::Security),
                 ("performance", Kind::Performance),
                 ("refactor", Kind::Refactor),
+                ("revert", Kind::Revert),
                 ("build", Kind::Build),
+                ("ci", Kind::Ci),
                 ("docs", Kind::Docs),
                 ("style", Kind::Style),
                 ("test", Kind::Test),
@@ -169,7 +220,8 @@ mod tests {
 
         #[test]
         fn errors_on_invalid_variant() {
-            for value in ["feat", "fi x", "perf", "doc", "testing"] {
+            for value in ["feat", "fi x", "perf", "doc", "testing", "sec", "cd"]
+            {
                 let res = Kind::from_str(value);
                 assert!(matches!(res, Err(Error::Kind)));
             }
@@ -183,4 +235,28 @@ mod tests {
             }
         }
     }
+
+    mod from_str_lenient {
+        use crate::changeset::change::{Error, Kind, Result};
+
+        #[test]
+        fn handles_any_casing() -> Result {
+            for (value, kind) in [
+                ("FEATURE", Kind::Feature),
+                ("Fix", Kind::Fix),
+                ("ci", Kind::Ci),
+            ] {
+                assert_eq!(Kind::from_str_lenient(value)?, kind);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_variant() {
+            for value in ["Feat", "PERF"] {
+                let res = Kind::from_str_lenient(value);
+                assert!(matches!(res, Err(Error::Kind)));
+            }
+        }
+    }
 }
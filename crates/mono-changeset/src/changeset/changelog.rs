@@ -25,15 +25,20 @@
 
 //! Changelog.
 
-use std::collections::BTreeMap;
+use semver::Version;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use mono_repository::{Forge, Id};
+
 use super::revision::Revision;
 use super::scopes::Scopes;
 use super::Changeset;
 
+pub mod file;
 mod section;
 
+pub use file::File;
 pub use section::{Category, Section};
 
 // ----------------------------------------------------------------------------
@@ -45,7 +50,8 @@ pub use section::{Category, Section};
 /// Changelogs are temporary views on a [`Changeset`][], grouping revisions by
 /// category, which is deduced from the [`Kind`] of change, and ignoring any
 /// changes irrelevant for versioning. Breaking changes are always grouped
-/// into their own section, which comes first.
+/// into their own section, which comes first, unless the order of sections
+/// is changed with [`Changelog::with_order`].
 ///
 /// The changelog is solely intended for printing, which is why it implements
 /// [`fmt::Display`]. The output format is Markdown, as supported by GitHub.
@@ -55,8 +61,42 @@ pub use section::{Category, Section};
 pub struct Changelog<'a> {
     /// Scope set.
     scopes: &'a Scopes,
+    /// Forge for linking commits and issues.
+    forge: Option<&'a Forge>,
     /// Sections grouped by category.
     sections: BTreeMap<Category, Section<'a>>,
+    /// Order of sections.
+    order: &'a [Category],
+    /// Release date as Unix timestamp.
+    timestamp: Option<i64>,
+}
+
+/// Changelog in GitHub Releases format.
+struct GitHubRelease<'a> {
+    /// Changelog.
+    changelog: &'a Changelog<'a>,
+    /// Links to commits and issues.
+    links: Option<Links>,
+    /// Version.
+    version: &'a Version,
+}
+
+/// Links to commits and issues, relative to a repository URL.
+pub(crate) struct Links {
+    /// Repository URL.
+    url: String,
+    /// Path prefix of commits and issues, which is `/-` for GitLab.
+    prefix: &'static str,
+}
+
+/// Changelog with YAML front matter.
+struct Frontmatter<'a> {
+    /// Changelog.
+    changelog: &'a Changelog<'a>,
+    /// Version.
+    version: &'a Version,
+    /// Release date, if any.
+    date: Option<&'a str>,
 }
 
 // ----------------------------------------------------------------------------
@@ -69,18 +109,68 @@ impl Changeset<'_> {
     pub fn to_changelog(&self) -> Changelog<'_> {
         let mut changelog = Changelog {
             scopes: &self.scopes,
+            forge: self.forge.as_ref(),
             sections: BTreeMap::default(),
+            order: &[],
+            timestamp: None,
         };
 
         // Extend changelog with all revisions
         changelog.extend(&self.revisions);
         changelog
     }
+
+    /// Creates a changelog from the changeset, dated by the given timestamp.
+    #[inline]
+    #[must_use]
+    pub fn to_changelog_with_date(&self, timestamp: i64) -> Changelog<'_> {
+        self.to_changelog().with_date(timestamp)
+    }
+
+    /// Creates a changelog from the changeset, limited to the given scope.
+    ///
+    /// Only revisions affecting the scope are included, so the changelog can
+    /// be used for package-specific release notes. If the scope is not part of
+    /// the scope set, the changelog is empty.
+    #[must_use]
+    pub fn to_changelog_for_scope(&self, scope: &str) -> Changelog<'_> {
+        let mut changelog = Changelog {
+            scopes: &self.scopes,
+            forge: self.forge.as_ref(),
+            sections: BTreeMap::default(),
+            order: &[],
+            timestamp: None,
+        };
+
+        // Extend changelog with revisions affecting the scope
+        changelog.extend(self.revisions_for_scope(scope));
+        changelog
+    }
 }
 
 // ----------------------------------------------------------------------------
 
 impl<'a> Changelog<'a> {
+    /// Sets the release date, which is rendered as part of the heading.
+    ///
+    /// The timestamp is usually obtained from the commit the version's tag
+    /// points to, and the date is always given in UTC.
+    #[must_use]
+    pub fn with_date(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the order of sections, e.g., as given in the configuration.
+    ///
+    /// Sections are rendered in the given order of categories, followed by all
+    /// categories not part of it, which retain their default order.
+    #[must_use]
+    pub fn with_order(mut self, order: &'a [Category]) -> Self {
+        self.order = order;
+        self
+    }
+
     /// Adds a revision to the changelog.
     ///
     /// Note that only relevant changes are included in the changelog, which
@@ -91,15 +181,111 @@ impl<'a> Changelog<'a> {
         let change = revision.change();
 
         // Determine section category, create section and add revision - note
-        // that we need to pass the scopes for rendering, as only indices are
-        // stored, and not all types of changes are featured in the changelog,
-        // so we skip those that are not
+        // that we need to pass the scopes and forge for rendering, as only
+        // indices are stored, and not all types of changes are featured in the
+        // changelog, so we skip those that are not
         if let Some(category) = change.into() {
             self.sections
                 .entry(category)
                 .or_insert_with(|| category.into())
-                .add(revision, self.scopes);
+                .add(revision, self.scopes, self.forge);
+        }
+    }
+
+    /// Returns all sections in the configured order.
+    fn sections(&self) -> Vec<&Section<'a>> {
+        let mut sections = self.sections.values().collect::<Vec<_>>();
+        sections.sort_by_key(|section| {
+            let category = section.category();
+            let opt = self.order.iter().position(|&next| next == category);
+            (opt.unwrap_or(self.order.len()), category)
+        });
+
+        // Return sections
+        sections
+    }
+}
+
+impl Changelog<'_> {
+    /// Renders the changelog in the format used by GitHub Releases.
+    ///
+    /// The heading is the version, followed by a "What's Changed" section, in
+    /// which all categories are nested, and breaking changes are marked with
+    /// a warning sign. Commits and issues are linked to the given repository
+    /// URL, e.g., `https://github.com/zensical/mono`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Changeset;
+    /// use mono_project::{Python, Workspace};
+    /// use semver::Version;
+    /// # let dir = tempfile::TempDir::new()?;
+    /// # std::fs::write(dir.path().join("pyproject.toml"), "")?;
+    /// # let workspace = Workspace::<Python>::resolve(dir.path())?;
+    ///
+    /// // Create changeset and render empty changelog
+    /// let changeset = Changeset::new(&workspace)?;
+    /// let changelog = changeset.to_changelog();
+    /// let markdown = changelog.to_github_releases_markdown(
+    ///     "https://github.com/zensical/mono",
+    ///     &Version::new(1, 0, 0),
+    /// );
+    /// assert_eq!(markdown, "## 1.0.0");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_github_releases_markdown(
+        &self, repo_url: &str, version: &Version,
+    ) -> String {
+        let repo_url = repo_url.trim().trim_end_matches('/');
+        GitHubRelease {
+            changelog: self,
+            links: (!repo_url.is_empty()).then(|| Links::new(repo_url)),
+            version,
         }
+        .to_string()
+    }
+
+    /// Renders the changelog with YAML front matter for static site generators.
+    ///
+    /// The front matter includes the version as title, the given date, e.g.,
+    /// as obtained from [`file::date`], whether the changelog contains breaking
+    /// changes, and the names of all affected scopes. If no date is given, it's
+    /// omitted. The changelog follows in the same format as rendered by
+    /// [`fmt::Display`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Changeset;
+    /// use mono_project::{Python, Workspace};
+    /// use semver::Version;
+    /// # let dir = tempfile::TempDir::new()?;
+    /// # std::fs::write(dir.path().join("pyproject.toml"), "")?;
+    /// # let workspace = Workspace::<Python>::resolve(dir.path())?;
+    ///
+    /// // Create changeset and render empty changelog with front matter
+    /// let changeset = Changeset::new(&workspace)?;
+    /// let changelog = changeset.to_changelog();
+    /// let version = Version::new(1, 0, 0);
+    /// let date = Some("2024-01-15");
+    /// let markdown = changelog.to_markdown_with_frontmatter(&version, date);
+    /// assert!(markdown.starts_with("---\ntitle: \"1.0.0\"\n"));
+    /// assert!(markdown.contains("breaking: false\npackages: []\n---"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_markdown_with_frontmatter(
+        &self, version: &Version, date: Option<&str>,
+    ) -> String {
+        Frontmatter { changelog: self, version, date }.to_string()
     }
 }
 
@@ -118,10 +304,55 @@ impl Changelog<'_> {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+impl Links {
+    /// Creates links relative to the given repository URL.
+    ///
+    /// The URL is used as given, so links work for any host, even if its forge
+    /// can't be detected, which is only necessary to determine the prefix.
+    fn new(url: &str) -> Self {
+        let prefix = match Forge::from_url(url) {
+            Some(Forge::GitLab { .. }) => "/-",
+            _ => "",
+        };
+        Self { url: url.to_string(), prefix }
+    }
+
+    /// Returns the URL of the commit with the given identifier.
+    pub(crate) fn commit_url(&self, id: Id) -> String {
+        format!("{}{}/commit/{}", self.url, self.prefix, *id)
+    }
+
+    /// Returns the URL of the issue with the given number.
+    pub(crate) fn issue_url(&self, number: u32) -> String {
+        format!("{}{}/issues/{number}", self.url, self.prefix)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Quotes the given string for use in YAML front matter.
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).expect("invariant")
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
+impl From<&Forge> for Links {
+    /// Creates links relative to the repository URL of the given forge.
+    #[inline]
+    fn from(forge: &Forge) -> Self {
+        Self::new(&forge.to_string())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 impl<'a> Extend<&'a Revision<'a>> for Changelog<'a> {
     /// Extends the changelog with the given revisions.
     fn extend<T>(&mut self, iter: T)
@@ -141,10 +372,13 @@ impl fmt::Display for Changelog<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if !self.sections.is_empty() {
             f.write_str("## Changelog")?;
+            if let Some(timestamp) = self.timestamp {
+                write!(f, " — {}", file::date(timestamp))?;
+            }
         }
 
         // Write all sections
-        for section in self.sections.values() {
+        for section in self.sections() {
             f.write_str("\n\n")?;
             section.fmt(f)?;
         }
@@ -153,3 +387,261 @@ impl fmt::Display for Changelog<'_> {
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for GitHubRelease<'_> {
+    /// Formats the changelog in GitHub Releases format for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "## {}", self.version)?;
+        if !self.changelog.is_empty() {
+            f.write_str("\n\n### What's Changed")?;
+        }
+
+        // Write all sections, marking breaking changes with a warning sign
+        for section in self.changelog.sections() {
+            f.write_str("\n\n#### ")?;
+            if section.category() == Category::Breaking {
+                f.write_str("⚠️ ")?;
+            }
+            writeln!(f, "{}", section.category())?;
+
+            // Write all items, each on a new line
+            for item in section.items() {
+                f.write_str("\n- ")?;
+                item.fmt_with_links(f, self.links.as_ref())?;
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Frontmatter<'_> {
+    /// Formats the changelog with YAML front matter for display.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut packages = BTreeSet::new();
+        for section in self.changelog.sections.values() {
+            for item in section.items() {
+                packages.extend(item.scopes().iter().copied());
+            }
+        }
+
+        // Write front matter, quoting strings as JSON, which is valid YAML
+        let version = quote(&self.version.to_string());
+        f.write_str("---\n")?;
+        writeln!(f, "title: {version}")?;
+        writeln!(f, "version: {version}")?;
+        if let Some(date) = self.date {
+            writeln!(f, "date: {}", quote(date))?;
+        }
+        let breaking =
+            self.changelog.sections.contains_key(&Category::Breaking);
+        writeln!(f, "breaking: {breaking}")?;
+        if packages.is_empty() {
+            f.write_str("packages: []\n")?;
+        } else {
+            f.write_str("packages:\n")?;
+            for name in packages {
+                writeln!(f, "  - {}", quote(name))?;
+            }
+        }
+        f.write_str("---")?;
+
+        // Write changelog, if not empty
+        if !self.changelog.is_empty() {
+            write!(f, "\n\n{}", self.changelog)?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod to_changelog_for_scope {
+        use mono_project::{Python, Workspace};
+        use mono_repository::testing::Fixture;
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        /// Creates a workspace with two packages, each with a single change.
+        fn fixture() -> Result<Fixture, Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[tool.mono]\nmembers = [\"packages/*\"]\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+            for name in ["a", "b"] {
+                fixture.write(
+                    format!("packages/{name}/pyproject.toml"),
+                    format!(
+                        "[project]\nname = \"{name}\"\nversion = \"1.0.0\"\n"
+                    ),
+                )?;
+                fixture.commit(format!("chore: add {name}"))?;
+            }
+            fixture.write("packages/a/a.py", "")?;
+            fixture.commit("feature: add feature to a")?;
+            fixture.write("packages/b/b.py", "")?;
+            fixture.commit("fix: fix bug in b")?;
+            Ok(fixture)
+        }
+
+        #[test]
+        fn handles_non_overlapping_scopes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.extend(repo.commits(..)?.flatten())?;
+
+            // Ensure each changelog only contains its own changes
+            let a = changeset.to_changelog_for_scope("a");
+            let b = changeset.to_changelog_for_scope("b");
+            assert_eq!((a.len(), b.len()), (1, 1));
+            assert!(a.to_string().contains("add feature to a"));
+            assert!(!a.to_string().contains("fix bug in b"));
+            assert!(b.to_string().contains("fix bug in b"));
+            assert!(!b.to_string().contains("add feature to a"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_unknown_scope() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.extend(repo.commits(..)?.flatten())?;
+            assert!(changeset.to_changelog_for_scope("c").is_empty());
+            Ok(())
+        }
+    }
+
+    mod to_github_releases_markdown {
+        use mono_project::{Python, Workspace};
+        use mono_repository::testing::Fixture;
+        use semver::Version;
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        #[test]
+        fn handles_repository_urls() -> Result<(), Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[project]\nname = \"a\"\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.commit("fix: fix bug (#1)")?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            let commit = repo.find("HEAD")?;
+            let id = commit.id();
+            changeset.add(commit)?;
+
+            // Render changelog for hosts that can't be detected, which must
+            // be linked to the given URL, as well as for GitLab
+            let changelog = changeset.to_changelog();
+            let version = Version::new(1, 0, 1);
+            for (url, prefix) in [
+                ("https://git.example.com/a/b", "https://git.example.com/a/b"),
+                ("https://gitlab.com/a/b/", "https://gitlab.com/a/b/-"),
+            ] {
+                let markdown =
+                    changelog.to_github_releases_markdown(url, &version);
+                let commit = format!("({prefix}/commit/{}", *id);
+                assert!(markdown.contains(&commit));
+                assert!(markdown.contains(&format!("({prefix}/issues/1)")));
+            }
+            Ok(())
+        }
+    }
+
+    mod to_markdown_with_frontmatter {
+        use mono_project::{Python, Workspace};
+        use mono_repository::testing::Fixture;
+        use semver::Version;
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        /// Creates a workspace with two packages, one with a breaking change.
+        fn fixture() -> Result<Fixture, Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[tool.mono]\nmembers = [\"packages/*\"]\n",
+            )?;
+            for name in ["a", "b"] {
+                fixture.write(
+                    format!("packages/{name}/pyproject.toml"),
+                    format!(
+                        "[project]\nname = \"{name}\"\nversion = \"1.0.0\"\n"
+                    ),
+                )?;
+            }
+            fixture.commit("chore: initial commit")?;
+            fixture.write("packages/a/a.py", "")?;
+            fixture.commit("feature!: add breaking feature to a")?;
+            fixture.write("packages/b/b.py", "")?;
+            fixture.commit("fix: fix bug in b")?;
+            Ok(fixture)
+        }
+
+        #[test]
+        fn handles_breaking_changes() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.add(repo.find("HEAD")?)?;
+            changeset.add(repo.find("HEAD~1")?)?;
+
+            // Render changelog, and check front matter
+            let changelog = changeset.to_changelog();
+            let version = Version::new(2, 0, 0);
+            let markdown =
+                changelog.to_markdown_with_frontmatter(&version, Some("x"));
+            assert!(markdown.starts_with(
+                "---\ntitle: \"2.0.0\"\nversion: \"2.0.0\"\ndate: \"x\"\n\
+                 breaking: true\npackages:\n  - \"a\"\n  - \"b\"\n---\n\n"
+            ));
+            assert!(markdown.contains("add breaking feature to a"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_missing_date() -> Result<(), Box<dyn Error>> {
+            let fixture = fixture()?;
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.add(repo.find("HEAD")?)?;
+
+            // Render changelog without date, which must be omitted
+            let changelog = changeset.to_changelog();
+            let version = Version::new(1, 0, 1);
+            let markdown =
+                changelog.to_markdown_with_frontmatter(&version, None);
+            assert!(markdown.starts_with(
+                "---\ntitle: \"1.0.1\"\nversion: \"1.0.1\"\n\
+                 breaking: false\npackages:\n  - \"b\"\n---\n\n"
+            ));
+            Ok(())
+        }
+    }
+}
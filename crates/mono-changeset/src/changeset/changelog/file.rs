@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Changelog file.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+mod error;
+
+pub use error::{Error, Result};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Changelog file.
+///
+/// Changelog files accumulate the changelogs of all released versions, newest
+/// first, each introduced by a heading with the version's tag and release
+/// date, e.g., `## v1.2.0 — 2024-01-15`. Any content before the first entry,
+/// like a title or description, is retained, so new entries are inserted
+/// right after it.
+#[derive(Debug)]
+pub struct File {
+    /// File path.
+    path: PathBuf,
+    /// File content.
+    content: String,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl File {
+    /// Attempts to read a changelog file from the given path.
+    ///
+    /// If the file doesn't exist, the changelog file is empty, and is created
+    /// once it's written.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`] if the file can't be read.
+    pub fn read<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Return changelog file
+        Ok(Self {
+            path: path.to_path_buf(),
+            content,
+        })
+    }
+
+    /// Returns whether the changelog file contains an entry for the tag.
+    #[must_use]
+    pub fn contains<S>(&self, tag: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let heading = format!("## {}", tag.as_ref());
+        self.content.lines().any(|line| {
+            line.strip_prefix(&heading).is_some_and(|rest| {
+                rest.is_empty() || rest.starts_with(char::is_whitespace)
+            })
+        })
+    }
+
+    /// Prepends an entry for the given tag to the changelog file.
+    ///
+    /// The tag is expected to be formatted according to the tag format of the
+    /// repository, so headings match the tags of the versions. The timestamp
+    /// is used to derive the release date for the heading, which is always
+    /// given in UTC, since it's the date of the version's tag.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::VersionExists`], if the changelog file
+    /// already contains an entry for the given tag.
+    pub fn prepend<T, S>(&mut self, tag: T, timestamp: i64, entry: S) -> Result
+    where
+        T: AsRef<str>,
+        S: AsRef<str>,
+    {
+        let tag = tag.as_ref();
+        if self.contains(tag) {
+            return Err(Error::VersionExists(tag.to_string()));
+        }
+
+        // Split content at the first entry, so we retain any leading content
+        let index = self
+            .content
+            .match_indices("## ")
+            .map(|(index, _)| index)
+            .find(|&index| index == 0 || self.content[..index].ends_with('\n'))
+            .unwrap_or(self.content.len());
+        let (head, tail) = self.content.split_at(index);
+
+        // Assemble content, separating all non-empty parts with blank lines
+        let heading = format!("## {tag} — {}", date(timestamp));
+        let parts = [head.trim_end(), &heading, entry.as_ref().trim(), tail];
+        let mut content = parts
+            .into_iter()
+            .map(str::trim_end)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        // Update content, ensuring a trailing line feed
+        content.push('\n');
+        self.content = content;
+        Ok(())
+    }
+
+    /// Writes the changelog file.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`] if the file can't be written.
+    pub fn write(&self) -> Result {
+        fs::write(&self.path, &self.content)?;
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl File {
+    /// Returns a reference to the file path.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns a reference to the file content.
+    #[inline]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Formats the given Unix timestamp as a date in `YYYY-MM-DD` format.
+///
+/// This is an implementation of the `civil_from_days` algorithm by Howard
+/// Hinnant, which converts days since the Unix epoch into a proleptic Gregorian
+/// calendar date, so we don't need to pull in a date and time library.
+#[must_use]
+pub fn date(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+
+    // Compute year of era, day of year, and month starting from March
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+
+    // Compute day, month, and year, shifting months back to January
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod date {
+        use crate::changeset::changelog::file::date;
+
+        #[test]
+        fn handles_timestamps() {
+            assert_eq!(date(0), "1970-01-01");
+            assert_eq!(date(951_782_400), "2000-02-29");
+            assert_eq!(date(1_705_276_800 + 86_399), "2024-01-15");
+            assert_eq!(date(-1), "1969-12-31");
+        }
+    }
+
+    mod prepend {
+        use std::path::PathBuf;
+
+        use crate::changeset::changelog::file::{Error, File, Result};
+
+        #[test]
+        fn handles_title() -> Result {
+            let mut file = File {
+                path: PathBuf::from("CHANGELOG.md"),
+                content: String::from("# Changelog\n\n## v1.0.0 — x\n\nA\n"),
+            };
+            file.prepend("v1.1.0", 1_705_276_800, "B\n")?;
+            assert_eq!(
+                file.content(),
+                "# Changelog\n\n\
+                 ## v1.1.0 — 2024-01-15\n\nB\n\n\
+                 ## v1.0.0 — x\n\nA\n"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_existing_version() {
+            let mut file = File {
+                path: PathBuf::from("CHANGELOG.md"),
+                content: String::from("## v1.0.0 — 2024-01-15\n\nA\n"),
+            };
+            let res = file.prepend("v1.0.0", 0, "B");
+            assert!(matches!(res, Err(Error::VersionExists(_))));
+        }
+
+        #[test]
+        fn handles_tag_format() -> Result {
+            let mut file = File {
+                path: PathBuf::from("CHANGELOG.md"),
+                content: String::from("## v1.0.0 — x\n\nA\n"),
+            };
+            file.prepend("release-1.0.0", 1_705_276_800, "B")?;
+            assert_eq!(
+                file.content(),
+                "## release-1.0.0 — 2024-01-15\n\nB\n\n\
+                 ## v1.0.0 — x\n\nA\n"
+            );
+            Ok(())
+        }
+    }
+}
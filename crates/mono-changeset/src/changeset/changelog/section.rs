@@ -52,6 +52,18 @@ pub struct Section<'a> {
 
 #[allow(clippy::must_use_candidate)]
 impl Section<'_> {
+    /// Returns the section category.
+    #[inline]
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Returns a reference to the items.
+    #[inline]
+    pub(crate) fn items(&self) -> &[Item<'_>] {
+        &self.items
+    }
+
     /// Returns the number of items.
     #[inline]
     pub fn len(&self) -> usize {
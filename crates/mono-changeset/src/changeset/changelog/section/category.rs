@@ -25,20 +25,31 @@
 
 //! Section category.
 
+use serde::Deserialize;
 use std::fmt;
+use std::str::FromStr;
 
 use crate::changeset::change::Kind;
-use crate::changeset::Change;
+use crate::changeset::{Change, Error, Result};
 
 // ----------------------------------------------------------------------------
 // Enums
 // ----------------------------------------------------------------------------
 
 /// Section category.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// The order of variants defines the default order of sections in the
+/// [`Changelog`][], which can be changed with [`Changelog::with_order`][].
+///
+/// [`Changelog`]: crate::changeset::changelog::Changelog
+/// [`Changelog::with_order`]: crate::changeset::changelog::Changelog::with_order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(try_from = "String")]
 pub enum Category {
     /// Breaking changes.
     Breaking,
+    /// Security fixes.
+    Security,
     /// Features.
     Feature,
     /// Bug fixes.
@@ -49,6 +60,25 @@ pub enum Category {
     Refactor,
 }
 
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Category {
+    /// Returns the short identifier, e.g., as used in configuration.
+    #[must_use]
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Category::Breaking => "breaking",
+            Category::Security => "security",
+            Category::Feature => "feature",
+            Category::Fix => "fix",
+            Category::Performance => "performance",
+            Category::Refactor => "refactor",
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -68,8 +98,11 @@ impl From<&Change> for Option<Category> {
             match change.kind() {
                 Kind::Feature => Category::Feature,
                 Kind::Fix => Category::Fix,
+                Kind::Security => Category::Security,
                 Kind::Performance => Category::Performance,
                 Kind::Refactor => Category::Refactor,
+                // Reverts undo unintended changes, so we list them as fixes
+                Kind::Revert => Category::Fix,
                 _ => return None,
             }
         };
@@ -81,11 +114,68 @@ impl From<&Change> for Option<Category> {
 
 // ----------------------------------------------------------------------------
 
+impl FromStr for Category {
+    type Err = Error;
+
+    /// Attempts to create a section category from its short identifier.
+    ///
+    /// # Errors
+    ///
+    /// This methods return [`Error::Category`], if the string does not
+    /// correspond to a valid [`Category`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::changelog::Category;
+    ///
+    /// // Create section category from string
+    /// let category: Category = "breaking".parse()?;
+    /// assert_eq!(category.identifier(), "breaking");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "breaking" => Ok(Category::Breaking),
+            "security" => Ok(Category::Security),
+            "feature" => Ok(Category::Feature),
+            "fix" => Ok(Category::Fix),
+            "performance" => Ok(Category::Performance),
+            "refactor" => Ok(Category::Refactor),
+            _ => Err(Error::Category(value.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Category {
+    type Error = Error;
+
+    /// Attempts to create a section category from its short identifier.
+    ///
+    /// This implementation allows to deserialize categories from strings, so
+    /// that [`FromStr`] is the only place where identifiers are parsed.
+    ///
+    /// # Errors
+    ///
+    /// This methods return [`Error::Category`], if the string does not
+    /// correspond to a valid [`Category`] variant.
+    #[inline]
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 impl fmt::Display for Category {
     /// Formats the section category for display.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Category::Breaking => f.write_str("Breaking changes"),
+            Category::Security => f.write_str("Security fixes"),
             Category::Feature => f.write_str("Features"),
             Category::Fix => f.write_str("Bug fixes"),
             Category::Performance => f.write_str("Performance improvements"),
@@ -93,3 +183,42 @@ impl fmt::Display for Category {
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_str {
+        use std::str::FromStr;
+
+        use crate::changeset::changelog::Category;
+        use crate::changeset::{Error, Result};
+
+        #[test]
+        fn handles_valid_variants() -> Result {
+            for category in [
+                Category::Breaking,
+                Category::Security,
+                Category::Feature,
+                Category::Fix,
+                Category::Performance,
+                Category::Refactor,
+            ] {
+                let value = category.identifier();
+                assert_eq!(Category::from_str(value)?, category);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_variant() {
+            for value in ["feat", "Fix", "perf", "breaking changes"] {
+                let res = Category::from_str(value);
+                assert!(matches!(res, Err(Error::Category(_))));
+            }
+        }
+    }
+}
@@ -27,6 +27,9 @@
 
 use std::fmt::{self, Write};
 
+use mono_repository::Forge;
+
+use crate::changeset::changelog::Links;
 use crate::changeset::revision::Revision;
 use crate::changeset::scopes::Scopes;
 
@@ -43,6 +46,8 @@ pub struct Item<'a> {
     revision: &'a Revision<'a>,
     /// Affected scopes.
     scopes: Vec<&'a str>,
+    /// Forge for linking commits and issues.
+    forge: Option<&'a Forge>,
 }
 
 // ----------------------------------------------------------------------------
@@ -51,28 +56,41 @@ pub struct Item<'a> {
 
 impl<'a> Section<'a> {
     /// Adds a revision to the section item.
-    pub fn add(&mut self, revision: &'a Revision, scopes: &'a Scopes) {
+    pub fn add(
+        &mut self, revision: &'a Revision, scopes: &'a Scopes,
+        forge: Option<&'a Forge>,
+    ) {
         // Obtain names of affected scopes
         let mut affected = Vec::new();
         for &index in revision.scopes() {
-            let (_, name) = &scopes[index];
-            affected.push(name.as_str());
+            affected.extend(scopes.get_name(index));
         }
 
         // Create item and add to section
-        self.items.push(Item { revision, scopes: affected });
+        self.items.push(Item {
+            revision,
+            scopes: affected,
+            forge,
+        });
     }
 }
 
-// ----------------------------------------------------------------------------
-// Trait implementations
-// ----------------------------------------------------------------------------
+impl Item<'_> {
+    /// Returns the names of the affected scopes.
+    pub(crate) fn scopes(&self) -> &[&str] {
+        &self.scopes
+    }
 
-impl fmt::Display for Item<'_> {
-    /// Formats the section item for display.
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    /// Formats the section item, linking commits and issues, if given.
+    pub(crate) fn fmt_with_links(
+        &self, f: &mut fmt::Formatter, links: Option<&Links>,
+    ) -> fmt::Result {
         let id = self.revision.commit().id();
-        id.short().fmt(f)?;
+        if let Some(links) = links {
+            write!(f, "[{}]({})", id.short(), links.commit_url(id))?;
+        } else {
+            f.write_str(&id.short())?;
+        }
 
         // Write affected scopes
         if !self.scopes.is_empty() {
@@ -98,9 +116,13 @@ impl fmt::Display for Item<'_> {
         let references = change.references();
         if !references.is_empty() {
             f.write_str(" (")?;
-            for (i, reference) in references.iter().enumerate() {
-                f.write_char('#')?;
-                reference.fmt(f)?;
+            for (i, &reference) in references.iter().enumerate() {
+                if let Some(links) = links {
+                    let url = links.issue_url(reference);
+                    write!(f, "[#{reference}]({url})")?;
+                } else {
+                    write!(f, "#{reference}")?;
+                }
 
                 // Write comma if not last
                 if i < references.len() - 1 {
@@ -110,7 +132,29 @@ impl fmt::Display for Item<'_> {
             f.write_char(')')?;
         }
 
+        // Write breaking change description on the following lines, using a
+        // hard line break, and indenting it to keep it part of the list item
+        if let Some(description) = change.breaking_description() {
+            for line in description.lines() {
+                f.write_str("\\\n  ")?;
+                f.write_str(line)?;
+            }
+        }
+
         // No errors occurred
         Ok(())
     }
 }
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Item<'_> {
+    /// Formats the section item for display.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let links = self.forge.map(Links::from);
+        self.fmt_with_links(f, links.as_ref())
+    }
+}
@@ -36,7 +36,46 @@ use std::path::PathBuf;
 /// Configuration.
 #[derive(Debug, Default, Deserialize)]
 pub struct Config {
-    /// Additional scopes.
+    /// Additional scopes, which are virtual if the path is empty.
     #[serde(default)]
-    pub scopes: BTreeMap<String, PathBuf>,
+    pub scopes: BTreeMap<String, Scope>,
+    /// Handling of changes referencing unknown scopes.
+    #[serde(default)]
+    pub strict_scopes: Strictness,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Strictness of scope checks.
+///
+/// Changes may reference scopes that are not part of the scope set, e.g., due
+/// to typos, which can be reported, e.g., `strict_scopes = "warn"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strictness {
+    /// Unknown scopes are ignored.
+    #[default]
+    Off,
+    /// Unknown scopes are collected, so they can be reported as warnings.
+    Warn,
+    /// Unknown scopes are rejected with an error.
+    Error,
+}
+
+/// Scope configuration.
+///
+/// Scopes are either given as a path, e.g., `docs = "docs/**"`, or through a
+/// regular expression, e.g., `gen = { pattern = "gen/.*\\.rs" }`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Scope {
+    /// Scope matched by path.
+    Path(PathBuf),
+    /// Scope matched by regular expression.
+    Pattern {
+        /// Regular expression.
+        pattern: String,
+    },
 }
@@ -48,12 +48,21 @@ pub enum Error {
     /// Scope set error.
     #[error(transparent)]
     Scopes(#[from] scopes::Error),
+    /// JSON error.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
     /// Missing signature.
     #[error("missing signature")]
     Signature,
     /// Missing summary.
     #[error("missing summary")]
     Summary,
+    /// Unknown scope.
+    #[error("unknown scope: {0}")]
+    Scope(String),
+    /// Unknown section category.
+    #[error("unknown category: {0}")]
+    Category(String),
 }
 
 // ----------------------------------------------------------------------------
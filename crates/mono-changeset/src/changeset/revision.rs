@@ -27,14 +27,21 @@
 
 use std::cmp;
 use std::collections::BTreeSet;
+use std::ops::Bound;
 use std::str::FromStr;
 
-use mono_repository::Commit;
+use mono_project::version::Increment;
+use mono_repository::{Commit, Id, Repository};
 
 use super::change::Change;
-use super::error::Result;
+use super::config::Strictness;
+use super::error::{Error, Result};
 use super::Changeset;
 
+mod owned;
+
+pub use owned::OwnedRevision;
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -73,42 +80,155 @@ impl Revision<'_> {
     pub fn scopes(&self) -> &[usize] {
         &self.scopes
     }
+
+    /// Returns the author of the original commit.
+    #[inline]
+    pub fn author(&self) -> String {
+        self.commit.author()
+    }
+
+    /// Returns the co-authors of the original commit, e.g., `Name <email>`.
+    ///
+    /// Co-authors are read from the `Co-authored-by` trailers of the commit.
+    /// As trailers borrow from the message, we return owned strings.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Repository`] if the trailers of the commit
+    /// can't be parsed.
+    pub fn co_authors(&self) -> Result<Vec<String>> {
+        let trailers = self.commit.trailers()?;
+        let iter = trailers.co_authors().map(ToString::to_string);
+        Ok(iter.collect())
+    }
+
+    /// Returns the timestamp of the original commit.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.commit.timestamp()
+    }
+
+    /// Updates the given version increments for all affected scopes.
+    ///
+    /// The increment of each affected scope is only raised, never lowered, so
+    /// it always denotes the highest increment of all revisions applied.
+    pub(crate) fn update(&self, increments: &mut [Option<Increment>]) {
+        let increment = self.change.as_increment();
+        for &index in &self.scopes {
+            increments[index] = cmp::max(increments[index], increment);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
 
 impl<'a> Changeset<'a> {
+    /// Returns owned copies of all revisions, e.g., for serialization.
+    #[must_use]
+    pub fn to_owned_revisions(&self) -> Vec<OwnedRevision> {
+        let iter = self.revisions.iter();
+        let iter = iter.map(|revision| (revision, &self.scopes).into());
+
+        // Collect and return owned revisions
+        iter.collect()
+    }
+
     /// Adds a commit to the changeset.
     ///
+    /// Merge commits are always skipped, even if their summary is valid, as
+    /// their deltas are misleading when determining the affected scopes.
+    ///
     /// # Errors
     ///
     /// This method returns [`Error::Repository`][] if the commit deltas can't
     /// be retrieved. If the commit summary couldn't be parsed, the commit will
     /// be ignored, since there are several types of commits that will not make
-    /// it into the changeset, e.g., merge commits.
+    /// it into the changeset, e.g., merge commits. If strict scopes are set to
+    /// error, [`Error::Scope`][] is returned for changes referencing a scope
+    /// that is not part of the scope set, and if set to warn, the scope is
+    /// recorded, so it can be retrieved with [`Changeset::unknown_scopes`].
     ///
     /// [`Error::Repository`]: crate::changeset::Error::Repository
+    /// [`Error::Scope`]: crate::changeset::Error::Scope
     pub fn add(&mut self, commit: Commit<'a>) -> Result {
-        if let Ok(change) = Change::from_str(commit.summary()) {
-            // Retrieve affected scopes from commit
+        if commit.is_merge() {
+            return Ok(());
+        }
+
+        // Parse change from commit summary, and ignore commit if invalid
+        if let Ok(mut change) = Change::from_str(commit.summary()) {
+            if let Some(body) = commit.body() {
+                change.apply_body(body);
+            }
+
+            // Ensure scope is known, if strict scopes are enabled
+            if let Some(scope) = change
+                .scope()
+                .filter(|scope| self.scopes.get_by_name(scope).is_none())
+            {
+                match self.strict_scopes {
+                    Strictness::Off => {}
+                    Strictness::Warn => {
+                        self.unknown_scopes.insert(scope.to_string());
+                    }
+                    Strictness::Error => {
+                        return Err(Error::Scope(scope.to_string()));
+                    }
+                }
+            }
+
+            // Retrieve affected scopes from commit, attributing each path to
+            // all equally specific longest matching scopes, so enclosing
+            // scopes, e.g., of a root package, aren't affected by changes in
+            // nested scopes, but scopes sharing a path are, and add the scope
+            // of the change if it's virtual, as those never match any path
             let mut scopes = BTreeSet::new();
             for delta in commit.deltas()? {
-                scopes.extend(self.scopes.get(delta.path()));
+                let matches = self.scopes.get_all(delta.path());
+                if let Some(&first) = matches.first() {
+                    let specificity = self.scopes.specificity(first);
+                    scopes.extend(matches.into_iter().take_while(|&index| {
+                        self.scopes.specificity(index) == specificity
+                    }));
+                }
             }
-
-            // Update increments for affected scopes
-            let increment = change.as_increment();
-            for &index in &scopes {
-                self.increments[index] =
-                    cmp::max(self.increments[index], increment);
+            if let Some(index) = change
+                .scope()
+                .and_then(|scope| self.scopes.get_by_name(scope))
+                .filter(|&index| self.scopes.is_virtual(index))
+            {
+                scopes.insert(index);
             }
 
-            // Create revision and add to changeset
-            self.revisions.push(Revision {
+            // Create revision, update increments for affected scopes, and add
+            // revision to changeset
+            let revision = Revision {
                 commit,
                 change,
                 scopes: scopes.into_iter().collect(),
-            });
+            };
+            revision.update(&mut self.increments);
+            self.revisions.push(revision);
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Adds all commits after `from` up to `to`, or `HEAD` if not given.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Repository`][] if the commits or their
+    /// deltas can't be retrieved.
+    ///
+    /// [`Error::Repository`]: crate::changeset::Error::Repository
+    pub fn add_range(
+        &mut self, repository: &'a Repository, from: Id, to: Option<Id>,
+    ) -> Result {
+        let start = to.map_or(Bound::Unbounded, Bound::Included);
+        for commit in repository.commits((start, Bound::Excluded(from)))? {
+            self.add(commit?)?;
         }
 
         // No errors occurred
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Owned revision.
+
+use serde::Serialize;
+
+use mono_repository::commit::CommitSnapshot;
+
+use crate::changeset::scopes::Scopes;
+
+use super::Revision;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Owned revision.
+///
+/// Revisions borrow their commit from the repository, which is why they can't
+/// be serialized directly. Owned revisions contain all relevant information of
+/// the commit, and resolve scope indices to names, so they can be serialized
+/// and exported, e.g., for passing changesets between CI jobs.
+#[derive(Debug, Serialize)]
+pub struct OwnedRevision {
+    /// Short commit identifier.
+    pub id: String,
+    /// Commit summary.
+    pub summary: String,
+    /// Commit body.
+    pub body: Option<String>,
+    /// Commit author.
+    pub author: String,
+    /// Commit timestamp.
+    pub timestamp: i64,
+    /// Names of affected scopes.
+    pub scopes: Vec<String>,
+    /// Referenced issues.
+    pub issues: Vec<u32>,
+    /// Change kind, e.g., `feature`.
+    pub change_kind: String,
+    /// Whether the change is breaking.
+    pub is_breaking: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl From<(&Revision<'_>, &Scopes)> for OwnedRevision {
+    /// Creates an owned revision from a revision, resolving scope names.
+    fn from((revision, scopes): (&Revision<'_>, &Scopes)) -> Self {
+        let iter = revision
+            .scopes()
+            .iter()
+            .filter_map(|&index| scopes.get_name(index))
+            .map(ToString::to_string);
+
+        // Copy commit information from snapshot, but use the short identifier
+        let CommitSnapshot {
+            summary,
+            body,
+            author,
+            timestamp,
+            ..
+        } = revision.commit().snapshot();
+        let change = revision.change();
+
+        // Create owned revision
+        Self {
+            id: revision.commit().id().short(),
+            summary,
+            body,
+            author,
+            timestamp,
+            scopes: iter.collect(),
+            issues: change.references().to_vec(),
+            change_kind: change.kind().to_string(),
+            is_breaking: change.is_breaking(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from {
+        use mono_project::{Python, Workspace};
+        use mono_repository::testing::Fixture;
+        use std::error::Error;
+
+        use crate::changeset::Changeset;
+
+        #[test]
+        fn handles_revision() -> Result<(), Box<dyn Error>> {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "pyproject.toml",
+                "[project]\nname = \"a\"\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+            fixture.write("a.py", "")?;
+            fixture.commit("feature!: add feature (#1)")?;
+
+            // Add commit and create owned revision
+            let repo = fixture.open()?;
+            let workspace = Workspace::<Python>::resolve(fixture.path())?;
+            let mut changeset = Changeset::new(&workspace)?;
+            changeset.add(repo.find("HEAD")?)?;
+            let revisions = changeset.to_owned_revisions();
+            let revision = &revisions[0];
+            assert_eq!(revision.id.len(), 7);
+            assert_eq!(revision.summary, "feature!: add feature (#1)");
+            assert_eq!(revision.author, "Jane");
+            assert_eq!(revision.scopes, ["a"]);
+            assert_eq!(revision.issues, [1]);
+            assert_eq!(revision.change_kind, "feature");
+            assert!(revision.is_breaking);
+            Ok(())
+        }
+    }
+}
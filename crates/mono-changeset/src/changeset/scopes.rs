@@ -26,9 +26,10 @@
 //! Scope set.
 
 use globset::GlobSet;
-use std::fmt;
+use regex_automata::meta::Regex;
 use std::ops::Index;
 use std::path::{Path, PathBuf};
+use std::{cmp, fmt};
 
 mod builder;
 mod error;
@@ -45,10 +46,24 @@ pub use error::{Error, Result};
 /// Scopes are used to associate changes with non-overlapping paths in a git
 /// repository, where a list of paths is matched through a [`GlobSet`]. When
 /// two paths overlap, one path must be the prefix of another path. Thus, we
-/// return the longer path as the matching scope.
+/// return the more specific path as the matching scope, which is the one
+/// with more literal components, i.e., components without wildcards.
+///
+/// Scopes can also be matched through regular expressions, for packages that
+/// don't correspond to simple directory subtrees, which must match the entire
+/// path. They are indexed after all scopes with paths, so the indices of the
+/// latter are always stable, and ranked by the literal components of their
+/// pattern in the same way.
+///
+/// Additionally, virtual scopes can be registered, which are not associated
+/// with a path, and thus only recognized by name. They are indexed last.
 pub struct Scopes {
     /// Registered scopes.
     paths: Vec<(PathBuf, String)>,
+    /// Registered regex scopes, together with their specificity.
+    patterns: Vec<(Regex, String, usize)>,
+    /// Registered virtual scopes.
+    virtuals: Vec<String>,
     /// Glob set.
     globs: GlobSet,
 }
@@ -75,6 +90,10 @@ impl Scopes {
 
     /// Returns the longest matching scope for the given path.
     ///
+    /// The longest matching scope is the most specific one, i.e., the one with
+    /// the most literal components. If multiple scopes are equally specific,
+    /// the scope that was registered first is returned.
+    ///
     /// # Examples
     ///
     /// ```
@@ -92,18 +111,158 @@ impl Scopes {
     ///
     /// // Create path and obtain longest matching scope
     /// let path = Path::new("crates/mono/Cargo.toml");
-    /// assert_eq!(scopes.get(&path), Some(0));
+    /// assert_eq!(scopes.get_longest(&path), Some(0));
     /// # Ok(())
     /// # }
     /// ```
+    pub fn get_longest<P>(&self, path: P) -> Option<usize>
+    where
+        P: AsRef<Path>,
+    {
+        let iter = self.matches(path.as_ref()).into_iter();
+        let iter = iter.min_by_key(|&(index, len)| (cmp::Reverse(len), index));
+        iter.map(|(index, _)| index)
+    }
+
+    /// Returns the longest matching scope for the given path.
+    ///
+    /// This method is an alias for [`Scopes::get_longest`].
+    #[deprecated(note = "use `Scopes::get_longest` or `Scopes::get_all`")]
+    #[inline]
     pub fn get<P>(&self, path: P) -> Option<usize>
     where
         P: AsRef<Path>,
     {
-        self.globs.matches(path).into_iter().max_by_key(|&index| {
-            let (path, _) = &self.paths[index];
-            path.components().count()
-        })
+        self.get_longest(path)
+    }
+
+    /// Returns all matching scopes for the given path, longest match first.
+    ///
+    /// This is necessary for paths that genuinely belong to multiple scopes,
+    /// e.g., a shared directory of protocol definitions for multiple services.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    /// use std::path::Path;
+    ///
+    /// // Create scope set builder and add overlapping paths
+    /// let mut builder = Scopes::builder();
+    /// builder.add("proto/**", "service-a")?;
+    /// builder.add("proto/b/**", "service-b")?;
+    ///
+    /// // Create scope set and obtain all matching scopes
+    /// let scopes = builder.build()?;
+    /// let path = Path::new("proto/b/service.proto");
+    /// assert_eq!(scopes.get_all(&path), [1, 0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_all<P>(&self, path: P) -> Vec<usize>
+    where
+        P: AsRef<Path>,
+    {
+        let mut matches = self.matches(path.as_ref());
+        matches.sort_by_key(|&(index, len)| (cmp::Reverse(len), index));
+        matches.into_iter().map(|(index, _)| index).collect()
+    }
+
+    /// Returns the scope with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builder and add path
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    ///
+    /// // Create scope set from builder
+    /// let scopes = builder.build()?;
+    /// assert_eq!(scopes.get_by_name("mono"), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<usize> {
+        self.names().position(|scope| scope == name)
+    }
+
+    /// Returns the name of the scope at the given index.
+    #[must_use]
+    pub fn get_name(&self, index: usize) -> Option<&str> {
+        self.names().nth(index)
+    }
+
+    /// Returns the path of the scope at the given index.
+    ///
+    /// Regex and virtual scopes have no path, so [`None`] is returned for them,
+    /// as well as for indices that are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    /// use std::path::Path;
+    ///
+    /// // Create scope set builder and add path and virtual scope
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    /// builder.add_virtual("ci")?;
+    ///
+    /// // Create scope set from builder
+    /// let scopes = builder.build()?;
+    /// assert_eq!(scopes.get_path(0), Some(Path::new("crates/mono/**")));
+    /// assert_eq!(scopes.get_path(1), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get_path(&self, index: usize) -> Option<&Path> {
+        self.paths.get(index).map(|(path, _)| path.as_path())
+    }
+
+    /// Returns whether the scope at the given index is a virtual scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builder and add path and virtual scope
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    /// builder.add_virtual("ci")?;
+    ///
+    /// // Create scope set from builder
+    /// let scopes = builder.build()?;
+    /// assert_eq!(scopes.get_by_name("ci"), Some(1));
+    /// assert!(scopes.is_virtual(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_virtual(&self, index: usize) -> bool {
+        (self.paths.len() + self.patterns.len()..self.len()).contains(&index)
+    }
+
+    /// Creates an iterator over the scope names in registration order, with
+    /// regex and virtual scopes following all scopes with paths.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        let paths = self.paths.iter().map(|(_, name)| name.as_str());
+        let patterns = self.patterns.iter().map(|(_, name, _)| name.as_str());
+        let virtuals = self.virtuals.iter().map(String::as_str);
+        paths.chain(patterns).chain(virtuals)
     }
 }
 
@@ -112,16 +271,95 @@ impl Scopes {
     /// Returns the number of scopes.
     #[inline]
     pub fn len(&self) -> usize {
-        self.paths.len()
+        self.paths.len() + self.patterns.len() + self.virtuals.len()
     }
 
     /// Returns whether there are any scopes.
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.paths.is_empty()
+            && self.patterns.is_empty()
+            && self.virtuals.is_empty()
+    }
+}
+
+impl Scopes {
+    /// Returns the specificity of the scope at the given index, i.e., the
+    /// number of literal components, which is zero for virtual scopes.
+    pub(crate) fn specificity(&self, index: usize) -> usize {
+        if let Some((path, _)) = self.paths.get(index) {
+            glob_specificity(path)
+        } else {
+            let index = index - self.paths.len();
+            let pattern = self.patterns.get(index);
+            pattern.map_or(0, |&(_, _, specificity)| specificity)
+        }
+    }
+
+    /// Returns all matching scopes for the given path, together with their
+    /// specificity, i.e., the number of literal components, for comparison.
+    fn matches(&self, path: &Path) -> Vec<(usize, usize)> {
+        let globs = self.globs.matches(path).into_iter().map(|index| {
+            let (path, _) = &self.paths[index];
+            (index, glob_specificity(path))
+        });
+
+        // Match regex scopes against the entire path, as they're anchored at
+        // both ends, and use specificity determined from the pattern when the
+        // scope was added
+        let value = path.to_string_lossy();
+        let patterns = self.patterns.iter().enumerate().filter_map(
+            |(n, (regex, _, specificity))| {
+                let index = self.paths.len() + n;
+                regex
+                    .is_match(value.as_ref())
+                    .then_some((index, *specificity))
+            },
+        );
+
+        // Collect matches across both kinds of scopes
+        globs.chain(patterns).collect()
     }
 }
 
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the specificity of the given glob, i.e., the number of components
+/// that don't contain glob characters, e.g., `2` for `crates/*/src/**`.
+fn glob_specificity(path: &Path) -> usize {
+    let iter = path.components().filter(|component| {
+        let value = component.as_os_str().to_string_lossy();
+        !value.contains(['*', '?', '[', '{'])
+    });
+    iter.count()
+}
+
+/// Returns the specificity of the given regular expression, i.e., the number
+/// of components that only consist of literal or escaped characters, e.g.,
+/// `2` for `crates/[^/]+/src/.*\.rs`, so `.*` is the least specific pattern.
+fn regex_specificity(pattern: &str) -> usize {
+    let iter = pattern.split('/').filter(|component| {
+        let mut chars = component.chars();
+        while let Some(char) = chars.next() {
+            let literal = match char {
+                '\\' => {
+                    chars.next().is_some_and(|next| !next.is_alphanumeric())
+                }
+                _ => !"^$.*+?()[]{}|".contains(char),
+            };
+            if !literal {
+                return false;
+            }
+        }
+
+        // Component is literal, unless empty
+        !component.is_empty()
+    });
+    iter.count()
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -130,6 +368,14 @@ impl Index<usize> for Scopes {
     type Output = (PathBuf, String);
 
     /// Returns the scope at the given index.
+    ///
+    /// Use [`Scopes::get_path`] and [`Scopes::get_name`] for indices of regex
+    /// and virtual scopes, which can be obtained from [`Scopes::get_by_name`].
+    ///
+    /// # Panics
+    ///
+    /// Regex and virtual scopes have no path, so indexing them panics, as does
+    /// indexing out of bounds.
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         &self.paths[index]
@@ -143,6 +389,100 @@ impl fmt::Debug for Scopes {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Scope")
             .field("paths", &self.paths)
+            .field("patterns", &self.patterns)
+            .field("virtuals", &self.virtuals)
             .finish_non_exhaustive()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod add_regex {
+        use crate::changeset::scopes::{Error, Result, Scopes};
+
+        #[test]
+        fn errors_on_existing_name() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("crates/mono/**", "mono")?;
+            builder.add_regex(r"gen/.*", "gen")?;
+            for name in ["mono", "gen"] {
+                let res = builder.add_regex(r"src/.*", name);
+                assert!(matches!(res, Err(Error::NameExists)));
+            }
+            Ok(())
+        }
+    }
+
+    mod get_longest {
+        use std::path::Path;
+
+        use crate::changeset::scopes::{Result, Scopes};
+
+        #[test]
+        fn handles_glob_over_unspecific_regex() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("crates/mono/**", "mono")?;
+            builder.add_regex(".*", "all")?;
+            let scopes = builder.build()?;
+            let path = Path::new("crates/mono/src/lib.rs");
+            assert_eq!(scopes.get_longest(path), Some(0));
+            let path = Path::new("docs/index.md");
+            assert_eq!(scopes.get_longest(path), Some(1));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_specific_regex_over_glob() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("crates/**", "crates")?;
+            builder.add_regex(r"crates/[^/]+/src/.*\.rs", "rust")?;
+            let scopes = builder.build()?;
+            let path = Path::new("crates/mono/src/lib.rs");
+            assert_eq!(scopes.get_longest(path), Some(1));
+            assert_eq!(scopes.get_all(path), [1, 0]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_regex_matching_entire_path() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add_regex(r"crates/foo/.*", "foo")?;
+            let scopes = builder.build()?;
+            let path = Path::new("crates/foo/lib.rs");
+            assert_eq!(scopes.get_longest(path), Some(0));
+            let path = Path::new("crates/foobar/lib.rs");
+            assert_eq!(scopes.get_longest(path), None);
+            let path = Path::new("src/crates/foo/lib.rs");
+            assert_eq!(scopes.get_longest(path), None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_equally_specific_scopes() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("crates/*/src/**", "src")?;
+            builder.add_regex(r"crates/.*/src/.*", "rust")?;
+            let scopes = builder.build()?;
+            let path = Path::new("crates/mono/src/lib.rs");
+            assert_eq!(scopes.get_longest(path), Some(0));
+            Ok(())
+        }
+    }
+
+    mod regex_specificity {
+        use crate::changeset::scopes::regex_specificity;
+
+        #[test]
+        fn handles_literal_and_escaped_components() {
+            assert_eq!(regex_specificity(".*"), 0);
+            assert_eq!(regex_specificity(r"crates/[^/]+/src/.*\.rs"), 2);
+            assert_eq!(regex_specificity(r"gen/lib\.rs"), 2);
+            assert_eq!(regex_specificity(r"gen/\d+"), 1);
+        }
+    }
+}
@@ -26,10 +26,13 @@
 //! Scope set builder.
 
 use globset::{Glob, GlobSetBuilder};
+use regex_automata::meta::Regex;
 use std::path::{Path, PathBuf};
 
+use mono_project::{Manifest, Workspace};
+
 use super::error::{Error, Result};
-use super::Scopes;
+use super::{regex_specificity, Scopes};
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -40,8 +43,12 @@ use super::Scopes;
 pub struct Builder {
     /// Registered scopes.
     paths: Vec<(PathBuf, String)>,
-    /// Glob set builder.
-    globs: GlobSetBuilder,
+    /// Registered regex scopes, together with their specificity.
+    patterns: Vec<(Regex, String, usize)>,
+    /// Registered virtual scopes.
+    virtuals: Vec<String>,
+    /// Whether to reject overlapping paths.
+    strict: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -66,15 +73,44 @@ impl Builder {
     pub fn new() -> Self {
         Self {
             paths: Vec::new(),
-            globs: GlobSetBuilder::new(),
+            patterns: Vec::new(),
+            virtuals: Vec::new(),
+            strict: false,
+        }
+    }
+
+    /// Creates a scope set builder from the given workspace.
+    ///
+    /// Each package of the workspace is added as a scope, matching all files
+    /// inside the package's directory, named after the package.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Glob`] if a [`Glob`] can't be built, and
+    /// [`Error::PathExists`] if two packages share the same path and name.
+    pub fn from_workspace<T>(workspace: &Workspace<T>) -> Result<Self>
+    where
+        T: Manifest,
+    {
+        let mut builder = Self::new();
+        for (path, name) in workspace.packages() {
+            builder.add(path.join("**"), name)?;
         }
+
+        // Return builder
+        Ok(builder)
     }
 
     /// Adds a scope to the scope set.
     ///
+    /// The same path can be registered under different names, e.g., for a
+    /// shared directory of protocol definitions for multiple services, so all
+    /// of those scopes are affected by changes to the path.
+    ///
     /// # Errors
     ///
-    /// This method returns [`Error::Glob`] if the [`Glob`] can't be built.
+    /// This method returns [`Error::Glob`] if the [`Glob`] can't be built, and
+    /// [`Error::PathExists`] if the path is already registered with the name.
     ///
     /// # Examples
     ///
@@ -99,25 +135,295 @@ impl Builder {
             return Err(Error::PathAbsolute);
         }
 
-        // Ensure path does not already exist, as scopes must be unique
-        if self.paths.iter().any(|(candidate, _)| candidate == path) {
+        // Ensure path does not already exist with the same name, as scopes
+        // must be unique, but paths may be shared among multiple scopes
+        let name = name.into();
+        if self.contains(path, &name) {
             Err(Error::PathExists)
 
-        // Create glob and add to builder
+        // Ensure glob is valid, and add to builder - the glob set is created
+        // when building, so scopes can still be removed before
         } else {
-            self.paths.push((path.to_path_buf(), name.into()));
-            self.globs.add(Glob::new(&path.to_string_lossy())?);
+            Glob::new(&path.to_string_lossy())?;
+            self.paths.push((path.to_path_buf(), name));
 
             // Return builder for chaining
             Ok(self)
         }
     }
 
+    /// Removes all scopes with the given path from the scope set.
+    ///
+    /// Returns whether any scope was found, e.g., to exclude packages after
+    /// creating a builder from a workspace with [`Builder::from_workspace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builder, and add and remove scope
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    /// assert!(builder.remove("crates/mono/**"));
+    /// assert!(!builder.remove("crates/mono/**"));
+    /// assert!(builder.build()?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove<P>(&mut self, path: P) -> bool
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let len = self.paths.len();
+        self.paths.retain(|(candidate, _)| candidate != path);
+        self.paths.len() != len
+    }
+
+    /// Adds a regex scope to the scope set.
+    ///
+    /// Regex scopes are useful for packages that don't correspond to simple
+    /// directory subtrees. The pattern must match the entire path, i.e., it's
+    /// anchored at both ends, and its literal components are compared to those
+    /// of glob scopes, so the most specific match wins across both kinds.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Regex`] if the pattern is invalid, and
+    /// [`Error::NameExists`] if the name is already taken by another scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    /// use std::path::Path;
+    ///
+    /// // Create scope set builder and add glob and regex scopes
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/**", "crates")?;
+    /// builder.add_regex(r"crates/[^/]+/src/.*\.rs", "rust")?;
+    ///
+    /// // Create scope set and obtain longest matching scope
+    /// let scopes = builder.build()?;
+    /// let path = Path::new("crates/a/src/lib.rs");
+    /// assert_eq!(scopes.get_longest(path), Some(1));
+    /// let path = Path::new("crates/a/Cargo.toml");
+    /// assert_eq!(scopes.get_longest(path), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_regex<N>(&mut self, pattern: &str, name: N) -> Result<&mut Self>
+    where
+        N: Into<String>,
+    {
+        let name = name.into();
+        if self.contains_name(&name) {
+            return Err(Error::NameExists);
+        }
+
+        // Add regex scope anchored at both ends, so it must match the entire
+        // path, and return builder for chaining
+        let regex =
+            Regex::new(&format!("^(?:{pattern})$")).map_err(Box::new)?;
+        self.patterns
+            .push((regex, name, regex_specificity(pattern)));
+        Ok(self)
+    }
+
+    /// Adds a virtual scope to the scope set.
+    ///
+    /// Virtual scopes are not associated with a path, so they never match any
+    /// file, but are recognized by name, e.g., for conceptual areas like `ci`,
+    /// which are only referenced through the scope of conventional commits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::NameExists`] if the name is already taken
+    /// by another scope, as virtual scopes are only recognized by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builder and add virtual scope
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    /// builder.add_virtual("ci")?;
+    ///
+    /// // Add virtual scope with name of existing scope
+    /// assert!(builder.add_virtual("mono").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_virtual<N>(&mut self, name: N) -> Result<&mut Self>
+    where
+        N: Into<String>,
+    {
+        let name = name.into();
+        if self.contains_name(&name) {
+            return Err(Error::NameExists);
+        }
+
+        // Add virtual scope and return builder for chaining
+        self.virtuals.push(name);
+        Ok(self)
+    }
+
+    /// Merges the scopes of the given builder into this builder.
+    ///
+    /// Scopes are appended in order, skipping paths that are registered with
+    /// the same name, as well as regex and virtual scopes whose names are
+    /// already taken by any scope, so the scopes of this builder take
+    /// precedence.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Glob`] if a [`Glob`] can't be built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builders with overlapping scopes
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/mono/**", "mono")?;
+    /// let mut other = Scopes::builder();
+    /// other.add("crates/mono/**", "mono")?;
+    /// other.add_virtual("ci")?;
+    ///
+    /// // Merge scope set builders and create scope set
+    /// builder.merge(other)?;
+    /// assert_eq!(builder.build()?.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(&mut self, other: Builder) -> Result<&mut Self> {
+        for (path, name) in other.paths {
+            if !self.contains(&path, &name) {
+                self.add(path, name)?;
+            }
+        }
+
+        // Append regex scopes and virtual scopes not yet registered
+        for (regex, name, specificity) in other.patterns {
+            if !self.contains_name(&name) {
+                self.patterns.push((regex, name, specificity));
+            }
+        }
+        for name in other.virtuals {
+            if !self.contains_name(&name) {
+                self.virtuals.push(name);
+            }
+        }
+
+        // Return builder for chaining
+        Ok(self)
+    }
+
+    /// Returns whether a scope with the given path and name is registered.
+    fn contains(&self, path: &Path, name: &str) -> bool {
+        let mut iter = self.paths.iter();
+        iter.any(|(candidate, other)| candidate == path && other == name)
+    }
+
+    /// Returns whether a scope with the given name is registered, regardless
+    /// of whether it's matched by path, regular expression, or virtual.
+    fn contains_name(&self, name: &str) -> bool {
+        let mut paths = self.paths.iter().map(|(_, name)| name);
+        let mut patterns = self.patterns.iter().map(|(_, name, _)| name);
+        paths.any(|candidate| candidate == name)
+            || patterns.any(|candidate| candidate == name)
+            || self.virtuals.iter().any(|candidate| candidate == name)
+    }
+
+    /// Sets whether to reject ambiguously overlapping paths when building.
+    ///
+    /// See [`Builder::validate_no_prefix_overlap`] for more information.
+    #[inline]
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Ensures that no path is an ambiguous string prefix of another path.
+    ///
+    /// Nested paths like `crates/foo` and `crates/foo/bar` are fine, as they
+    /// are resolved by picking the longest match, and so are paths separated
+    /// by punctuation like `crates/foo` and `crates/foo-bar`. However, paths
+    /// like `crates/foo` and `crates/foobar` look like they overlap at the
+    /// string level, which is easy to get wrong when writing globs by hand.
+    /// Glob components are ignored for comparison, e.g., `crates/foo/**`, so
+    /// the path of a root package, which is only `**`, never overlaps.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::PathOverlap`] if two paths overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_changeset::Scopes;
+    ///
+    /// // Create scope set builder and add adjacent paths
+    /// let mut builder = Scopes::builder();
+    /// builder.add("crates/foo/**", "foo")?;
+    /// builder.add("crates/foo-bar/**", "foo-bar")?;
+    /// assert!(builder.validate_no_prefix_overlap().is_ok());
+    ///
+    /// // Add nested path, which doesn't overlap
+    /// builder.add("crates/foo/bar/**", "bar")?;
+    /// assert!(builder.validate_no_prefix_overlap().is_ok());
+    ///
+    /// // Add path with the same string prefix, which overlaps
+    /// builder.add("crates/foobar/**", "foobar")?;
+    /// assert!(builder.validate_no_prefix_overlap().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_no_prefix_overlap(&self) -> Result {
+        let bases = self
+            .paths
+            .iter()
+            .map(|(path, _)| base(path).to_string_lossy().into_owned())
+            .filter(|base| !base.is_empty())
+            .collect::<Vec<_>>();
+
+        // Compare all pairs of paths in both directions, and report paths
+        // where one is a string prefix of the other that doesn't end at a
+        // boundary, i.e., it's continued by a letter or digit, not by `/`
+        for a in &bases {
+            for b in &bases {
+                if let Some(rest) = b.strip_prefix(a.as_str()) {
+                    if rest.starts_with(char::is_alphanumeric) {
+                        return Err(Error::PathOverlap(a.clone(), b.clone()));
+                    }
+                }
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
     /// Builds the scope set.
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Glob`] if the [`GlobSet`][] can't be built.
+    /// This method returns [`Error::Glob`] if the [`GlobSet`][] can't be built,
+    /// and [`Error::PathOverlap`] if strict mode is enabled and paths overlap.
     ///
     /// [`GlobSet`]: globset::GlobSet
     ///
@@ -138,13 +444,41 @@ impl Builder {
     /// # }
     /// ```
     pub fn build(self) -> Result<Scopes> {
+        if self.strict {
+            self.validate_no_prefix_overlap()?;
+        }
+
+        // Create glob set from registered scopes, which were already checked
+        // for validity when they were added
+        let mut globs = GlobSetBuilder::new();
+        for (path, _) in &self.paths {
+            globs.add(Glob::new(&path.to_string_lossy())?);
+        }
+
+        // Create and return scope set
         Ok(Scopes {
             paths: self.paths.into_iter().collect(),
-            globs: self.globs.build()?,
+            patterns: self.patterns,
+            virtuals: self.virtuals,
+            globs: globs.build()?,
         })
     }
 }
 
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the base of the given path, omitting all components from the first
+/// component that contains glob characters onwards.
+fn base(path: &Path) -> PathBuf {
+    let iter = path.components().take_while(|component| {
+        let value = component.as_os_str().to_string_lossy();
+        !value.contains(['*', '?', '[', '{'])
+    });
+    iter.collect()
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -165,3 +499,40 @@ impl Default for Builder {
         Self::new()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod validate_no_prefix_overlap {
+        use crate::changeset::scopes::{Error, Result, Scopes};
+
+        #[test]
+        fn handles_root_path() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("**", "root")?;
+            builder.add("crates/foo/**", "foo")?;
+            builder.strict(true);
+            builder.build()?;
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_string_prefix() -> Result {
+            let mut builder = Scopes::builder();
+            builder.add("crates/foo/**", "foo")?;
+            builder.add("crates/foobar/**", "foobar")?;
+            builder.strict(true);
+            let res = builder.build();
+            assert!(matches!(
+                res,
+                Err(Error::PathOverlap(a, b))
+                    if a == "crates/foo" && b == "crates/foobar"
+            ));
+            Ok(())
+        }
+    }
+}
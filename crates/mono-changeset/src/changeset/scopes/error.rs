@@ -38,12 +38,21 @@ pub enum Error {
     /// Globset error.
     #[error(transparent)]
     Glob(#[from] globset::Error),
+    /// Regex error.
+    #[error(transparent)]
+    Regex(#[from] Box<regex_automata::meta::BuildError>),
     /// Path must not start at '/'.
     #[error("path must not start at '/'")]
     PathAbsolute,
     /// Path already exists.
     #[error("path already exists")]
     PathExists,
+    /// Path overlaps with another path.
+    #[error("path '{0}' overlaps with path '{1}'")]
+    PathOverlap(String, String),
+    /// Name already exists.
+    #[error("name already exists")]
+    NameExists,
 }
 
 // ----------------------------------------------------------------------------
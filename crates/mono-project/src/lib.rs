@@ -31,6 +31,7 @@ mod project;
 
 pub use project::manifest::cargo::Cargo;
 pub use project::manifest::node::Node;
+pub use project::manifest::python::Python;
 pub use project::manifest::{self, Manifest};
 pub use project::version;
 pub use project::workspace::{self, Workspace};
@@ -29,7 +29,7 @@ use semver::Version;
 use std::fmt::Write;
 use std::iter::{Chain, Once};
 use std::path::{Path, PathBuf};
-use std::{fmt, fs, iter};
+use std::{fmt, iter};
 
 mod error;
 pub mod manifest;
@@ -75,19 +75,40 @@ where
         P: AsRef<Path>,
     {
         let path = path.as_ref();
-        let content = fs::read_to_string(path)?;
         Ok(Self {
             path: path.canonicalize()?,
-            manifest: content.parse()?,
+            manifest: T::read(path)?,
         })
     }
 
     /// Returns a reference to the path.
+    ///
+    /// Note that this is the path of the manifest file, not the directory of
+    /// the project, which can be obtained via [`Project::directory`].
     #[inline]
     pub fn path(&self) -> &Path {
         &self.path
     }
 
+    /// Returns a reference to the manifest path.
+    #[inline]
+    pub fn manifest_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns a reference to the directory containing the manifest.
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn directory(&self) -> &Path {
+        self.path.parent().expect("invariant")
+    }
+
+    /// Returns whether the project is a workspace root, i.e., has members.
+    #[inline]
+    pub fn is_workspace_root(&self) -> bool {
+        !self.manifest.members().is_empty()
+    }
+
     /// Returns a reference to the name.
     #[inline]
     pub fn name(&self) -> Option<&str> {
@@ -99,6 +120,18 @@ where
     pub fn version(&self) -> Option<&Version> {
         self.manifest.version()
     }
+
+    /// Returns whether the project is private, i.e., must not be published.
+    #[inline]
+    pub fn is_private(&self) -> bool {
+        self.manifest.is_private()
+    }
+
+    /// Creates an iterator over the names of all dependencies.
+    #[inline]
+    pub fn dependencies(&self) -> impl Iterator<Item = &str> {
+        self.manifest.dependencies()
+    }
 }
 
 // ----------------------------------------------------------------------------
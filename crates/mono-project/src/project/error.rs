@@ -53,9 +53,21 @@ pub enum Error {
     /// TOML edit error.
     #[error(transparent)]
     TomlEdit(#[from] toml_edit::TomlError),
+    /// Repository error.
+    #[error(transparent)]
+    Repository(#[from] mono_repository::Error),
     /// JSON error.
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    /// Version error.
+    #[error(transparent)]
+    Version(#[from] semver::Error),
+    /// Inherited version not defined by workspace.
+    #[error("package `{0}` inherits version, but no workspace defines it")]
+    Inheritance(String),
+    /// Circular dependency between packages.
+    #[error("circular dependency between packages: {}", .0.join(", "))]
+    Cycle(Vec<String>),
     /// Process exited with status.
     #[error("process exited with status {0}")]
     Status(process::ExitStatus),
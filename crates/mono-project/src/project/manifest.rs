@@ -30,12 +30,14 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::{fs, io};
 
 use super::error::{Error, Result};
 use super::workspace::Versions;
 
 pub mod cargo;
 pub mod node;
+pub mod python;
 
 // ----------------------------------------------------------------------------
 // Traits
@@ -62,9 +64,33 @@ pub trait Manifest: Debug + Resolver + Writer {
     /// Returns a reference to the version.
     fn version(&self) -> Option<&Version>;
 
+    /// Returns whether the version is inherited from the workspace.
+    ///
+    /// Some ecosystems like Rust allow packages to inherit their version from
+    /// the workspace manifest, which must then be updated instead.
+    #[inline]
+    fn inherits_version(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the package is private, i.e., must not be published.
+    #[inline]
+    fn is_private(&self) -> bool {
+        false
+    }
+
     /// Returns a reference to the members.
     fn members(&self) -> Cow<'_, [String]>;
 
+    /// Returns a reference to the member exclusions.
+    ///
+    /// Some ecosystems allow to exclude paths matched by member patterns, so
+    /// members matching any of the returned patterns are filtered out.
+    #[inline]
+    fn exclusions(&self) -> Cow<'_, [String]> {
+        Cow::Borrowed(&[])
+    }
+
     /// Creates an iterator over the dependencies.
     fn dependencies(&self) -> impl Iterator<Item = &str>;
 }
@@ -85,6 +111,44 @@ pub trait Resolver: FromStr<Err = Error> {
     fn resolve<P>(path: P) -> Result<PathBuf>
     where
         P: AsRef<Path>;
+
+    /// Reads the manifest from the given path.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`] if the file can't be read, or any
+    /// error as returned by the [`FromStr`] implementation.
+    #[inline]
+    fn read<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Self::read_with(path, read_to_string)
+    }
+
+    /// Reads the manifest from the given path with the given function.
+    ///
+    /// The function returns the content of the file at the given path, or
+    /// [`None`] if the file doesn't exist, which allows to read manifests from
+    /// sources other than disk, e.g., from the tree of a commit. The default
+    /// implementation reads and parses the file at the given path, but
+    /// implementors can override this method to consider further files, e.g.,
+    /// when the ecosystem allows to define workspaces separately.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`] if the file can't be read, or any
+    /// error as returned by the [`FromStr`] implementation.
+    fn read_with<P, F>(path: P, mut read: F) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path) -> Result<Option<String>>,
+    {
+        let content = read(path.as_ref())?;
+        content
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?
+            .parse()
+    }
 }
 
 /// Manifest writer.
@@ -108,3 +172,16 @@ pub trait Writer: Sized {
     where
         P: AsRef<Path>;
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Reads the file at the given path from disk, if it exists.
+fn read_to_string(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
@@ -29,6 +29,7 @@ use semver::Version;
 use serde::Deserialize;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str::FromStr;
@@ -40,7 +41,7 @@ use crate::project::{Error, Result};
 mod model;
 mod versions;
 
-use model::{Dependency, Package, Workspace};
+use model::{Dependency, Package, PackageVersion, Workspace};
 
 // ----------------------------------------------------------------------------
 // Enums
@@ -89,12 +90,31 @@ impl Manifest for Cargo {
     #[inline]
     fn version(&self) -> Option<&Version> {
         if let Cargo::Package { package, .. } = self {
-            Some(&package.version)
+            match &package.version {
+                PackageVersion::Version(version) => Some(version),
+                PackageVersion::Inherited(version) => Some(version),
+                PackageVersion::Workspace { .. } => None,
+            }
         } else {
             None
         }
     }
 
+    /// Returns whether the version is inherited from the workspace.
+    #[inline]
+    fn inherits_version(&self) -> bool {
+        matches!(
+            self,
+            Cargo::Package {
+                package: Package {
+                    version: PackageVersion::Inherited(_),
+                    ..
+                },
+                ..
+            }
+        )
+    }
+
     /// Returns a reference to the members.
     #[inline]
     fn members(&self) -> Cow<'_, [String]> {
@@ -129,6 +149,55 @@ impl Resolver for Cargo {
     {
         Ok(path.as_ref().join("Cargo.toml"))
     }
+
+    /// Reads the manifest from the given path with the given function.
+    ///
+    /// Packages can inherit their version from `[workspace.package]`, which is
+    /// why we search all parent directories for the workspace manifest, and
+    /// resolve the version from there, if necessary.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of the default implementation, this method
+    /// returns [`Error::Inheritance`] if no workspace defines the version.
+    fn read_with<P, F>(path: P, mut read: F) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path) -> Result<Option<String>>,
+    {
+        let path = path.as_ref();
+        let content = read(path)?
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        let mut manifest: Self = content.parse()?;
+
+        // Return manifest, unless the version is inherited from the workspace
+        let Cargo::Package { package, .. } = &mut manifest else {
+            return Ok(manifest);
+        };
+        if !matches!(package.version, PackageVersion::Workspace { .. }) {
+            return Ok(manifest);
+        }
+
+        // Search parent directories for the workspace manifest
+        for dir in path.ancestors().skip(2) {
+            let Some(content) = read(&dir.join("Cargo.toml"))? else {
+                continue;
+            };
+
+            // Resolve version from the first workspace that defines one
+            let root: Self = content.parse()?;
+            if let Cargo::Workspace { workspace } = root {
+                let opt = workspace.package.and_then(|package| package.version);
+                if let Some(version) = opt {
+                    package.version = PackageVersion::Inherited(version);
+                    return Ok(manifest);
+                }
+            }
+        }
+
+        // No workspace defines the version
+        Err(Error::Inheritance(package.name.clone()))
+    }
 }
 
 impl Writer for Cargo {
@@ -174,3 +243,102 @@ impl FromStr for Cargo {
         Ok(toml::from_str(value)?)
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod resolve {
+        use semver::Version;
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::version::Increment;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_inherited_version() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                (
+                    "",
+                    "[workspace]\nmembers = [\"crates/*\"]\n\
+                     [workspace.package]\nversion = \"1.0.0\"\n",
+                ),
+                (
+                    "crates/a",
+                    "[package]\nname = \"a\"\nversion.workspace = true\n",
+                ),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(dir.path().join(path).join("Cargo.toml"), content)?;
+            }
+
+            // Resolve workspace and check inherited version
+            let workspace = Workspace::<Cargo>::resolve(dir.path())?;
+            let versions = workspace.versions_map();
+            assert_eq!(versions.get("a"), Some(&&Version::new(1, 0, 0)));
+
+            // Bump version without synchronization, and check manifests
+            let versions = workspace.bump(&[Some(Increment::Minor)], false)?;
+            assert_eq!(versions.get("a"), Some(&Version::new(1, 1, 0)));
+            let workspace = Workspace::<Cargo>::resolve(dir.path())?;
+            let versions = workspace.versions_map();
+            assert_eq!(versions.get("a"), Some(&&Version::new(1, 1, 0)));
+            let content =
+                fs::read_to_string(dir.path().join("crates/a/Cargo.toml"))?;
+            assert!(content.contains("version.workspace = true"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_inherited_version_as_unit() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                (
+                    "",
+                    "[workspace]\nmembers = [\"crates/*\"]\n\
+                     [workspace.package]\nversion = \"1.0.0\"\n",
+                ),
+                (
+                    "crates/a",
+                    "[package]\nname = \"a\"\nversion.workspace = true\n",
+                ),
+                (
+                    "crates/b",
+                    "[package]\nname = \"b\"\nversion.workspace = true\n",
+                ),
+                (
+                    "crates/c",
+                    "[package]\nname = \"c\"\nversion = \"0.1.0\"\n\
+                     [dependencies]\nb = \"1.0.0\"\n",
+                ),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(dir.path().join(path).join("Cargo.toml"), content)?;
+            }
+
+            // Bump only the first package, which must bump the second as well
+            let workspace = Workspace::<Cargo>::resolve(dir.path())?;
+            let increments = [Some(Increment::Minor), None, None];
+            let versions = workspace.bump(&increments, false)?;
+            assert_eq!(versions.get("a"), Some(&Version::new(1, 1, 0)));
+            assert_eq!(versions.get("b"), Some(&Version::new(1, 1, 0)));
+            assert_eq!(versions.get("c"), None);
+
+            // Check that requirements match the shared version
+            let content =
+                fs::read_to_string(dir.path().join("crates/c/Cargo.toml"))?;
+            assert!(content.contains("b = \"1.1.0\""));
+            let workspace = Workspace::<Cargo>::resolve(dir.path())?;
+            let versions = workspace.versions_map();
+            assert_eq!(versions.get("b"), Some(&&Version::new(1, 1, 0)));
+            Ok(())
+        }
+    }
+}
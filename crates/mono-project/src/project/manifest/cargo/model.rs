@@ -46,6 +46,22 @@ pub enum Dependency {
     },
 }
 
+/// Cargo package version.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PackageVersion {
+    /// Version.
+    Version(Version),
+    /// Version inherited from workspace, which is not yet resolved.
+    Workspace {
+        /// Whether the version is inherited.
+        workspace: bool,
+    },
+    /// Version inherited from workspace.
+    #[serde(skip)]
+    Inherited(Version),
+}
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -58,6 +74,15 @@ pub struct Workspace {
     /// Workspace dependencies.
     #[serde(default)]
     pub dependencies: BTreeMap<String, Dependency>,
+    /// Workspace package data, inheritable by members.
+    pub package: Option<WorkspacePackage>,
+}
+
+/// Cargo workspace package data.
+#[derive(Debug, Deserialize)]
+pub struct WorkspacePackage {
+    /// Package version.
+    pub version: Option<Version>,
 }
 
 /// Cargo package.
@@ -66,5 +91,5 @@ pub struct Package {
     /// Package name.
     pub name: String,
     /// Package version.
-    pub version: Version,
+    pub version: PackageVersion,
 }
@@ -54,6 +54,7 @@ impl Versions<'_, Cargo> {
 
         // Apply updates to the document
         self.update_package_version(&mut doc);
+        self.update_workspace_package_version(&mut doc);
         self.update_workspace_dependencies(&mut doc);
         self.update_dependencies(&mut doc);
 
@@ -62,11 +63,17 @@ impl Versions<'_, Cargo> {
     }
 
     /// Updates `[package].version` with a new version.
+    ///
+    /// Versions inherited from the workspace, i.e., `version.workspace = true`,
+    /// are skipped, as `[workspace.package].version` is updated instead.
     fn update_package_version(&self, doc: &mut DocumentMut) {
         if let Some(package) = doc
             .get_mut("package")
             .and_then(|item| item.as_table_like_mut())
         {
+            if package.get("version").is_some_and(Item::is_table_like) {
+                return;
+            }
             if let Some(name) =
                 package.get("name").and_then(|item| item.as_str())
             {
@@ -77,6 +84,21 @@ impl Versions<'_, Cargo> {
         }
     }
 
+    /// Updates `[workspace.package].version` with the inherited version.
+    fn update_workspace_package_version(&self, doc: &mut DocumentMut) {
+        if let Some(package) = doc
+            .get_mut("workspace")
+            .and_then(|item| item.get_mut("package"))
+            .and_then(|item| item.as_table_like_mut())
+        {
+            if let Some(version) = self.inherited() {
+                if package.contains_key("version") {
+                    package.insert("version", value(version.to_string()));
+                }
+            }
+        }
+    }
+
     /// Updates `[workspace.dependencies]` with new versions.
     fn update_workspace_dependencies(&self, doc: &mut DocumentMut) {
         if let Some(table) = doc
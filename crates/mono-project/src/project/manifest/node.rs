@@ -29,6 +29,7 @@ use semver::{Version, VersionReq};
 use serde::Deserialize;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str::FromStr;
@@ -37,8 +38,11 @@ use crate::project::manifest::{Manifest, Resolver, Writer};
 use crate::project::workspace::Versions;
 use crate::project::{Error, Result};
 
+mod pnpm;
 mod versions;
 
+pub use pnpm::PnpmWorkspace;
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -56,12 +60,22 @@ pub struct Node {
     pub name: String,
     /// Package version.
     pub version: Version,
+    /// Package is private, i.e., must not be published.
+    #[serde(default)]
+    pub private: bool,
     /// Package workspace members.
     #[serde(default)]
     pub workspaces: Vec<String>,
     /// Package dependencies.
     #[serde(default)]
     pub dependencies: BTreeMap<String, VersionReq>,
+    /// Package peer dependencies, which often combine ranges with `||`, so we
+    /// keep them as strings, as they're only relevant for ordering.
+    #[serde(default)]
+    pub peer_dependencies: BTreeMap<String, String>,
+    /// Package workspace, as defined in `pnpm-workspace.yaml`.
+    #[serde(skip)]
+    pub pnpm_workspace: Option<PnpmWorkspace>,
 }
 
 // ----------------------------------------------------------------------------
@@ -81,16 +95,46 @@ impl Manifest for Node {
         Some(&self.version)
     }
 
+    /// Returns whether the package is private, i.e., must not be published.
+    #[inline]
+    fn is_private(&self) -> bool {
+        self.private
+    }
+
     /// Returns a reference to the members.
+    ///
+    /// If a `pnpm-workspace.yaml` file is present, its packages take precedence
+    /// over the workspaces defined in `package.json`.
     #[inline]
     fn members(&self) -> Cow<'_, [String]> {
-        Cow::Borrowed(&self.workspaces)
+        if let Some(workspace) = &self.pnpm_workspace {
+            Cow::Borrowed(&workspace.packages)
+        } else {
+            Cow::Borrowed(&self.workspaces)
+        }
+    }
+
+    /// Returns a reference to the member exclusions.
+    ///
+    /// Only pnpm supports exclusions, which are denoted by a leading `!` in
+    /// the `packages` list of `pnpm-workspace.yaml`.
+    #[inline]
+    fn exclusions(&self) -> Cow<'_, [String]> {
+        match &self.pnpm_workspace {
+            Some(workspace) => Cow::Borrowed(&workspace.exclusions),
+            None => Cow::Borrowed(&[]),
+        }
     }
 
     /// Creates an iterator over the dependencies.
+    ///
+    /// Peer dependencies are included, so that packages are released after
+    /// the packages they're meant to be used with.
     #[inline]
     fn dependencies(&self) -> impl Iterator<Item = &str> {
-        self.dependencies.keys().map(String::as_str)
+        let iter = self.dependencies.keys();
+        iter.chain(self.peer_dependencies.keys())
+            .map(String::as_str)
     }
 }
 
@@ -105,6 +149,30 @@ impl Resolver for Node {
     {
         Ok(path.as_ref().join("package.json"))
     }
+
+    /// Reads the manifest from the given path with the given function.
+    ///
+    /// In addition to `package.json`, this method reads `pnpm-workspace.yaml`
+    /// located next to it, if any, as pnpm keeps workspace members there.
+    fn read_with<P, F>(path: P, mut read: F) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path) -> Result<Option<String>>,
+    {
+        let path = path.as_ref();
+        let content = read(path)?
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        let mut manifest: Self = content.parse()?;
+
+        // Read pnpm workspace, if any
+        let file = path.with_file_name("pnpm-workspace.yaml");
+        if let Some(content) = read(&file)? {
+            manifest.pnpm_workspace = Some(content.parse()?);
+        }
+
+        // Return manifest
+        Ok(manifest)
+    }
 }
 
 impl Writer for Node {
@@ -122,17 +190,32 @@ impl Writer for Node {
     /// Note that this method will run `npm install --package-lock-only` to
     /// synchronize `package-lock.json` with the updated versions. This ensures
     /// that the lock file reflects the changes made to the manifest, or there
-    /// will be inconsistencies between the manifest and the lock file.
+    /// will be inconsistencies between the manifest and the lock file. If a
+    /// `pnpm-lock.yaml` is present, `pnpm install --lockfile-only` is used.
     fn sync<P>(path: P) -> Result
     where
         P: AsRef<Path>,
     {
-        // Explicitly update `package-lock.json` for synchronization
-        let status = Command::new("npm")
-            .args(["install", "--package-lock-only", "--ignore-scripts"])
-            .current_dir(path)
-            .stderr(Stdio::null())
-            .status()?;
+        let path = path.as_ref();
+
+        // Explicitly update `package-lock.json` or `pnpm-lock.yaml`
+        let mut command = if path.join("pnpm-lock.yaml").exists() {
+            let mut command = Command::new("pnpm");
+            command.args(["install", "--lockfile-only", "--ignore-scripts"]);
+            command
+        } else {
+            let mut command = Command::new("npm");
+            command.args([
+                "install",
+                "--package-lock-only",
+                "--ignore-scripts",
+            ]);
+            command
+        };
+
+        // Run command for synchronization
+        let status =
+            command.current_dir(path).stderr(Stdio::null()).status()?;
 
         // Return error with status code if unsuccessful
         status.success().then_some(()).ok_or(Error::Status(status))
@@ -150,3 +233,127 @@ impl FromStr for Node {
         Ok(serde_json::from_str(value)?)
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_str {
+        use std::str::FromStr;
+
+        use crate::project::manifest::node::Node;
+        use crate::project::manifest::Manifest;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_peer_dependencies() -> Result {
+            let node = Node::from_str(
+                r#"{
+                    "name": "foo",
+                    "version": "1.0.0",
+                    "dependencies": { "bar": "^1.0.0" },
+                    "peerDependencies": { "baz": "^1.0.0 || ^2.0.0" }
+                }"#,
+            )?;
+            assert_eq!(node.dependencies().collect::<Vec<_>>(), ["bar", "baz"]);
+            assert!(!node.is_private());
+            Ok(())
+        }
+
+        #[test]
+        fn handles_private() -> Result {
+            let node = Node::from_str(
+                r#"{ "name": "foo", "version": "1.0.0", "private": true }"#,
+            )?;
+            assert!(node.is_private());
+            Ok(())
+        }
+    }
+
+    mod resolve {
+        use semver::Version;
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::node::Node;
+        use crate::project::version::Increment;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_pnpm_workspace_with_exclusions() -> Result {
+            let dir = TempDir::new()?;
+            for (path, name) in [
+                ("", "root"),
+                ("packages/a", "a"),
+                ("packages/internal", "internal"),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(
+                    dir.path().join(path).join("package.json"),
+                    format!(r#"{{ "name": "{name}", "version": "1.0.0" }}"#),
+                )?;
+            }
+            fs::write(
+                dir.path().join("pnpm-workspace.yaml"),
+                "packages:\n  - packages/*\n  - '!packages/internal'\n",
+            )?;
+
+            // Resolve workspace and check that exclusions are filtered out
+            let workspace = Workspace::<Node>::resolve(dir.path())?;
+            assert_eq!(
+                workspace.versions_map().into_keys().collect::<Vec<_>>(),
+                ["a", "root"]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_peer_dependencies_on_bump() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                (
+                    "",
+                    r#"{ "name": "root", "version": "1.0.0",
+                         "workspaces": ["packages/*"] }"#,
+                ),
+                ("packages/a", r#"{ "name": "a", "version": "1.0.0" }"#),
+                (
+                    "packages/b",
+                    r#"{ "name": "b", "version": "1.0.0",
+                         "peerDependencies": { "a": "^0.9.0 || ^1.0.0" } }"#,
+                ),
+                (
+                    "packages/c",
+                    r#"{ "name": "c", "version": "1.0.0",
+                         "peerDependencies": { "a": "workspace:^" } }"#,
+                ),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(dir.path().join(path).join("package.json"), content)?;
+            }
+
+            // Bump major version of the first package
+            let workspace = Workspace::<Node>::resolve(dir.path())?;
+            let increments = workspace
+                .packages()
+                .map(|(_, name)| (name == "a").then_some(Increment::Major))
+                .collect::<Vec<_>>();
+            let versions = workspace.bump(&increments, false)?;
+            assert_eq!(versions.get("a"), Some(&Version::new(2, 0, 0)));
+
+            // Check that peer dependencies are retained verbatim, as they're
+            // compatibility ranges, which are maintained by hand
+            let content =
+                fs::read_to_string(dir.path().join("packages/b/package.json"))?;
+            assert!(content.contains(r#""a": "^0.9.0 || ^1.0.0""#));
+            let content =
+                fs::read_to_string(dir.path().join("packages/c/package.json"))?;
+            assert!(content.contains(r#""a": "workspace:^""#));
+            Ok(())
+        }
+    }
+}
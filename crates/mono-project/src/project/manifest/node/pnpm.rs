@@ -0,0 +1,158 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! pnpm workspace.
+
+use std::str::FromStr;
+
+use crate::project::{Error, Result};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// pnpm workspace.
+///
+/// pnpm doesn't read workspace members from `package.json`, but from a file
+/// called `pnpm-workspace.yaml`, which lists them under the `packages` key.
+/// As this is the only key we're interested in, we don't pull in a complete
+/// YAML parser, but only extract the list of packages, either given in block
+/// or in flow style. Exclusions, denoted by a leading `!`, are collected
+/// separately, so members matching them can be filtered out.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PnpmWorkspace {
+    /// Workspace packages.
+    pub packages: Vec<String>,
+    /// Workspace package exclusions.
+    pub exclusions: Vec<String>,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl FromStr for PnpmWorkspace {
+    type Err = Error;
+
+    /// Attempts to create a pnpm workspace from a string.
+    fn from_str(value: &str) -> Result<Self> {
+        let mut items = Vec::new();
+
+        // Search for the top-level `packages` key, and collect all items that
+        // follow, until we reach the next top-level key
+        let mut inside = false;
+        for line in value.lines() {
+            let line = line.split(" #").next().unwrap_or_default();
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            // Check for top-level keys, and handle lists in flow style
+            if !line.starts_with(char::is_whitespace) && !line.starts_with('-')
+            {
+                inside = false;
+                if let Some(rest) = line.strip_prefix("packages:") {
+                    let rest = rest.trim();
+                    if let Some(rest) = rest
+                        .strip_prefix('[')
+                        .and_then(|rest| rest.strip_suffix(']'))
+                    {
+                        items.extend(rest.split(',').filter_map(item));
+                    } else {
+                        inside = rest.is_empty();
+                    }
+                }
+
+            // Handle lists in block style
+            } else if inside {
+                if let Some(rest) = line.trim_start().strip_prefix('-') {
+                    items.extend(item(rest));
+                }
+            }
+        }
+
+        // Partition items into packages and exclusions
+        let (exclusions, packages): (Vec<_>, Vec<_>) =
+            items.into_iter().partition(|item| item.starts_with('!'));
+        let exclusions = exclusions
+            .into_iter()
+            .map(|item| item.trim_start_matches('!').to_string())
+            .collect();
+
+        // Return workspace
+        Ok(Self { packages, exclusions })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Extracts a package pattern from a list item, removing quotes.
+fn item(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(['"', '\'']);
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_str {
+        use std::str::FromStr;
+
+        use crate::project::manifest::node::PnpmWorkspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_block_style() -> Result {
+            let workspace = PnpmWorkspace::from_str(
+                "# comment\n\
+                 packages:\n  \
+                   - 'packages/*'\n  \
+                   - \"apps/*\" # comment\n  \
+                   - '!**/test/**'\n\
+                 catalog:\n  \
+                   - react\n",
+            )?;
+            assert_eq!(workspace.packages, ["packages/*", "apps/*"]);
+            assert_eq!(workspace.exclusions, ["**/test/**"]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_flow_style() -> Result {
+            let workspace = PnpmWorkspace::from_str(
+                "packages: ['packages/*', apps/*, '!packages/internal']\n",
+            )?;
+            assert_eq!(workspace.packages, ["packages/*", "apps/*"]);
+            assert_eq!(workspace.exclusions, ["packages/internal"]);
+            Ok(())
+        }
+    }
+}
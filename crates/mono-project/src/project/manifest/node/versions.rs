@@ -74,7 +74,10 @@ impl Versions<'_, Node> {
         }
     }
 
-    /// Updates `dependencies` and `devDependencies` with new versions
+    /// Updates `dependencies` and `devDependencies` with new versions.
+    ///
+    /// Note that `peerDependencies` are deliberately left untouched, as they
+    /// denote compatibility ranges, which are maintained by hand.
     fn update_dependencies(&self, doc: &mut Map<String, Value>) {
         for section in ["dependencies", "devDependencies"] {
             if let Some(map) =
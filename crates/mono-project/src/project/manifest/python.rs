@@ -0,0 +1,366 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Python manifest.
+
+use semver::Version;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use crate::project::manifest::{Manifest, Resolver, Writer};
+use crate::project::workspace::Versions;
+use crate::project::{Error, Result};
+
+mod model;
+mod versions;
+
+use model::{Project, Tool};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Python manifest.
+///
+/// Note that we only read parts of the manifest relevant to our use case, as
+/// we're solely interested in identifying package name, version, and workspace
+/// members, and dependencies, in order to bump versions. Packages can either
+/// be defined through the standard `[project]` table, or `[tool.poetry]`.
+#[derive(Debug, Deserialize)]
+pub struct Python {
+    /// Project data.
+    pub project: Option<Project>,
+    /// Tool data.
+    #[serde(default)]
+    pub tool: Tool,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Manifest for Python {
+    /// Returns a reference to the name.
+    ///
+    /// Projects that declare their version as dynamic, e.g., to derive it from
+    /// tags, can't be bumped, so they're not considered to be packages.
+    #[inline]
+    fn name(&self) -> Option<&str> {
+        if let Some(project) = &self.project {
+            let name = project.name.as_str();
+            project.version.as_ref().map(|_| name)
+        } else {
+            self.tool.poetry.as_ref().map(|poetry| poetry.name.as_str())
+        }
+    }
+
+    /// Returns a reference to the version.
+    #[inline]
+    fn version(&self) -> Option<&Version> {
+        if let Some(project) = &self.project {
+            project.version.as_ref()
+        } else {
+            self.tool.poetry.as_ref().map(|poetry| &poetry.version)
+        }
+    }
+
+    /// Returns a reference to the members.
+    ///
+    /// Members are read from `[tool.mono.members]`, if given, and otherwise
+    /// from the `include` paths of `[tool.poetry.packages]`.
+    fn members(&self) -> Cow<'_, [String]> {
+        if let Some(mono) = &self.tool.mono {
+            Cow::Borrowed(&mono.members)
+        } else if let Some(poetry) = &self.tool.poetry {
+            let iter =
+                poetry.packages.iter().map(|package| match &package.from {
+                    Some(from) => format!("{from}/{}", package.include),
+                    None => package.include.clone(),
+                });
+            Cow::Owned(iter.collect())
+        } else {
+            Cow::Borrowed(&[])
+        }
+    }
+
+    /// Creates an iterator over the dependencies.
+    #[inline]
+    fn dependencies(&self) -> impl Iterator<Item = &str> {
+        let project = self.project.iter().flat_map(|project| {
+            project
+                .dependencies
+                .iter()
+                .map(|value| requirement(value).0)
+        });
+
+        // Poetry dependencies are keyed by name, which also includes Python
+        // itself, so we chain both, as only workspace packages are relevant
+        let poetry =
+            self.tool.poetry.iter().flat_map(|poetry| {
+                poetry.dependencies.keys().map(String::as_str)
+            });
+        project.chain(poetry)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Resolver for Python {
+    /// Resolves the manifest path from the given path.
+    #[inline]
+    fn resolve<P>(path: P) -> Result<PathBuf>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(path.as_ref().join("pyproject.toml"))
+    }
+}
+
+impl Writer for Python {
+    /// Updates the given manifest's content with new package versions.
+    #[inline]
+    fn update<S>(content: S, versions: &Versions<Self>) -> Result<String>
+    where
+        S: AsRef<str>,
+    {
+        versions.update(content)
+    }
+
+    /// Synchronizes the manifest after update.
+    ///
+    /// Note that this method will run `uv lock --offline` to synchronize the
+    /// `uv.lock` file with the updated versions. Since not all projects make
+    /// use of `uv`, synchronization is skipped if it's not installed.
+    fn sync<P>(path: P) -> Result
+    where
+        P: AsRef<Path>,
+    {
+        // Explicitly update `uv.lock` for synchronization
+        let res = Command::new("uv")
+            .args(["lock", "--offline"])
+            .current_dir(path)
+            .stderr(Stdio::null())
+            .status();
+
+        // Skip synchronization if `uv` is not available
+        let status = match res {
+            Ok(status) => status,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Return error with status code if unsuccessful
+        status.success().then_some(()).ok_or(Error::Status(status))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl FromStr for Python {
+    type Err = Error;
+
+    /// Attempts to create a manifest from a string.
+    #[inline]
+    fn from_str(value: &str) -> Result<Self> {
+        Ok(toml::from_str(value)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Splits a PEP 508 requirement into the package name and the remainder.
+///
+/// Requirements start with the package name, which may be followed by extras,
+/// version specifiers and environment markers, e.g. `foo[bar]>=1.0; extra`.
+fn requirement(value: &str) -> (&str, &str) {
+    let value = value.trim_start();
+    let index = value
+        .find(|char: char| {
+            !(char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.'))
+        })
+        .unwrap_or(value.len());
+
+    // Return name and remainder
+    value.split_at(index)
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_str {
+        use semver::Version;
+        use std::str::FromStr;
+
+        use crate::project::manifest::python::Python;
+        use crate::project::manifest::Manifest;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_project() -> Result {
+            let python = Python::from_str(
+                "[project]\n\
+                 name = \"foo\"\n\
+                 version = \"1.0.0\"\n\
+                 dependencies = [\"bar[baz]>=1.0.0\", \"qux\"]\n",
+            )?;
+            assert_eq!(python.name(), Some("foo"));
+            assert_eq!(python.version(), Some(&Version::new(1, 0, 0)));
+            assert_eq!(
+                python.dependencies().collect::<Vec<_>>(),
+                ["bar", "qux"]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_poetry() -> Result {
+            let python = Python::from_str(
+                "[tool.poetry]\n\
+                 name = \"foo\"\n\
+                 version = \"1.0.0\"\n\
+                 packages = [{ include = \"bar\", from = \"src\" }]\n\
+                 [tool.poetry.dependencies]\n\
+                 python = \"^3.10\"\n",
+            )?;
+            assert_eq!(python.name(), Some("foo"));
+            assert_eq!(python.version(), Some(&Version::new(1, 0, 0)));
+            assert_eq!(python.members().as_ref(), ["src/bar"]);
+            assert_eq!(python.dependencies().collect::<Vec<_>>(), ["python"]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_workspace() -> Result {
+            let python = Python::from_str(
+                "[tool.mono]\n\
+                 members = [\"packages/*\"]\n",
+            )?;
+            assert_eq!(python.name(), None);
+            assert_eq!(python.version(), None);
+            assert_eq!(python.members().as_ref(), ["packages/*"]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_dynamic_version() -> Result {
+            let python = Python::from_str(
+                "[project]\n\
+                 name = \"foo\"\n\
+                 dynamic = [\"version\"]\n",
+            )?;
+            assert_eq!(python.name(), None);
+            assert_eq!(python.version(), None);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_pep440_version() -> Result {
+            for (value, expected) in [
+                ("1.0", "1.0.0"),
+                ("2", "2.0.0"),
+                ("1.0.0a1", "1.0.0-alpha.1"),
+                ("1.0b2", "1.0.0-beta.2"),
+                ("1.0.0-rc.3", "1.0.0-rc.3"),
+                ("1.0.0.dev4", "1.0.0-dev.4"),
+            ] {
+                let python = Python::from_str(&format!(
+                    "[project]\nname = \"foo\"\nversion = \"{value}\"\n"
+                ))?;
+                let version = Version::parse(expected)?;
+                assert_eq!(python.version(), Some(&version));
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_unsupported_version() {
+            for value in ["1!1.0", "1.0.post1", "1.0+local", "1.0.0.0"] {
+                let res = Python::from_str(&format!(
+                    "[project]\nname = \"foo\"\nversion = \"{value}\"\n"
+                ));
+                assert!(res.is_err());
+            }
+        }
+    }
+
+    mod resolve {
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::python::Python;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_workspace_with_dependency() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                ("", "[tool.mono]\nmembers = [\"packages/*\"]\n"),
+                (
+                    "packages/a",
+                    "[project]\nname = \"a\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\"b>=1.0.0\"]\n",
+                ),
+                (
+                    "packages/b",
+                    "[project]\nname = \"b\"\nversion = \"1.0.0\"\n",
+                ),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(
+                    dir.path().join(path).join("pyproject.toml"),
+                    content,
+                )?;
+            }
+
+            // Resolve workspace and traverse dependents in topological order
+            let workspace = Workspace::<Python>::resolve(dir.path())?;
+            let dependents = workspace.dependents()?;
+            let names = dependents
+                .iter()
+                .map(|node| dependents[node].name())
+                .collect::<Vec<_>>();
+            assert_eq!(names, [Some("b"), Some("a")]);
+
+            // Check versions, which are the same for all packages
+            assert!(workspace.is_uniform_version());
+            assert_eq!(
+                workspace.versions_map().into_keys().collect::<Vec<_>>(),
+                ["a", "b"]
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Python manifest model.
+
+use semver::{Prerelease, Version};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Python project, as defined in PEP 621.
+#[derive(Debug, Deserialize)]
+pub struct Project {
+    /// Project name.
+    pub name: String,
+    /// Project version, which is absent if declared as dynamic.
+    #[serde(default, deserialize_with = "deserialize_version")]
+    pub version: Option<Version>,
+    /// Project dependencies.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Python tool configuration.
+#[derive(Debug, Default, Deserialize)]
+pub struct Tool {
+    /// Poetry configuration.
+    pub poetry: Option<Poetry>,
+    /// Mono configuration.
+    pub mono: Option<Mono>,
+}
+
+/// Poetry configuration.
+#[derive(Debug, Deserialize)]
+pub struct Poetry {
+    /// Package name.
+    pub name: String,
+    /// Package version.
+    pub version: Version,
+    /// Package includes.
+    #[serde(default)]
+    pub packages: Vec<Include>,
+    /// Package dependencies.
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, toml::Value>,
+}
+
+/// Poetry package include.
+#[derive(Debug, Deserialize)]
+pub struct Include {
+    /// Include path.
+    pub include: String,
+    /// Include base path.
+    pub from: Option<String>,
+}
+
+/// Mono configuration.
+#[derive(Debug, Deserialize)]
+pub struct Mono {
+    /// Workspace members.
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Deserializes a PEP 440 version, mapping it to a semantic version.
+fn deserialize_version<'de, D>(
+    deserializer: D,
+) -> Result<Option<Version>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    pep440(&value).map(Some).ok_or_else(|| {
+        D::Error::custom(format!("unsupported PEP 440 version `{value}`"))
+    })
+}
+
+/// Maps a PEP 440 version to a semantic version.
+///
+/// Missing release components are filled with zeros, and pre-releases and
+/// development releases are mapped to their semantic version counterparts,
+/// e.g., `1.0a1` becomes `1.0.0-alpha.1`, and `1.0.dev2` becomes `1.0.0-dev.2`.
+/// Epochs, post-releases and local versions have no such counterpart, so
+/// [`None`] is returned for them, as well as for more than three components.
+fn pep440(value: &str) -> Option<Version> {
+    if let Ok(version) = Version::parse(value) {
+        return Some(version);
+    }
+
+    // Split release components from pre-release or development segment
+    let value = value.trim().to_ascii_lowercase();
+    let value = value.strip_prefix('v').unwrap_or(&value);
+    let index = value
+        .find(|char: char| !(char.is_ascii_digit() || char == '.'))
+        .unwrap_or(value.len());
+    let (release, rest) = value.split_at(index);
+    let release = release.trim_end_matches('.');
+
+    // Parse release components, filling missing ones with zeros
+    let mut parts = [0; 3];
+    let mut iter = release.split('.');
+    for part in &mut parts {
+        if let Some(value) = iter.next() {
+            *part = value.parse().ok()?;
+        }
+    }
+    if iter.next().is_some() {
+        return None;
+    }
+
+    // Map pre-release or development segment, e.g., `a1`, `-rc.2` or `.dev3`
+    let rest = rest.trim_start_matches(['.', '-', '_']);
+    let pre = if rest.is_empty() {
+        Prerelease::EMPTY
+    } else {
+        let index = rest
+            .find(|char: char| char.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (label, number) = rest.split_at(index);
+        let label = match label.trim_end_matches(['.', '-', '_']) {
+            "a" | "alpha" => "alpha",
+            "b" | "beta" => "beta",
+            "c" | "rc" | "pre" | "preview" => "rc",
+            "dev" => "dev",
+            _ => return None,
+        };
+        let number = match number {
+            "" => 0,
+            _ => number.parse::<u64>().ok()?,
+        };
+        Prerelease::new(&format!("{label}.{number}")).ok()?
+    };
+
+    // Return semantic version
+    let [major, minor, patch] = parts;
+    let mut version = Version::new(major, minor, patch);
+    version.pre = pre;
+    Some(version)
+}
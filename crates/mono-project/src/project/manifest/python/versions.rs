@@ -0,0 +1,304 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Python manifest update.
+
+use semver::Version;
+use toml_edit::{value, DocumentMut, Item, TableLike, Value};
+
+use crate::project::workspace::Versions;
+use crate::project::Result;
+
+use super::{requirement, Python};
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Comparison operators of version specifiers, longest first.
+const OPERATORS: [&str; 8] = ["===", "~=", "==", "!=", ">=", "<=", ">", "<"];
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Versions<'_, Python> {
+    /// Updates package versions in the given manifest content.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::TomlEdit`][] if parsing or printing fails.
+    ///
+    /// [`Error::TomlEdit`]: crate::project::Error::TomlEdit
+    pub fn update<S>(&self, content: S) -> Result<String>
+    where
+        S: AsRef<str>,
+    {
+        let content = content.as_ref();
+        let mut doc = content.parse::<DocumentMut>()?;
+
+        // Apply updates to the document
+        self.update_project(&mut doc);
+        self.update_poetry(&mut doc);
+
+        // Return updated document
+        Ok(doc.to_string())
+    }
+
+    /// Updates `[project].version` and `[project].dependencies`.
+    fn update_project(&self, doc: &mut DocumentMut) {
+        if let Some(project) = doc
+            .get_mut("project")
+            .and_then(|item| item.as_table_like_mut())
+        {
+            self.update_package_version(project);
+
+            // Update requirements with version specifiers: `foo>=1.0.0`
+            if let Some(array) = project
+                .get_mut("dependencies")
+                .and_then(|item| item.as_array_mut())
+            {
+                for item in array.iter_mut() {
+                    if let Some(updated) = item
+                        .as_str()
+                        .and_then(|value| self.update_requirement(value))
+                    {
+                        let decor = item.decor().clone();
+                        *item = Value::from(updated);
+                        *item.decor_mut() = decor;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Updates `[tool.poetry].version` and `[tool.poetry.dependencies]`.
+    fn update_poetry(&self, doc: &mut DocumentMut) {
+        if let Some(poetry) = doc
+            .get_mut("tool")
+            .and_then(|item| item.get_mut("poetry"))
+            .and_then(|item| item.as_table_like_mut())
+        {
+            self.update_package_version(poetry);
+
+            // Update dependencies, which are keyed by name
+            if let Some(table) = poetry
+                .get_mut("dependencies")
+                .and_then(|item| item.as_table_like_mut())
+            {
+                for (name, item) in table.iter_mut() {
+                    if let Some(version) = self.get_normalized(name.get()) {
+                        self.update_dependency(item, version);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Updates the package version in the given table.
+    fn update_package_version(&self, table: &mut dyn TableLike) {
+        if let Some(name) = table.get("name").and_then(|item| item.as_str()) {
+            if let Some(version) = self.get(name) {
+                table.insert("version", value(version.to_string()));
+            }
+        }
+    }
+
+    /// Returns the version for the given package name, comparing names in
+    /// their normalized form, as mandated by PEP 503.
+    fn get_normalized(&self, name: &str) -> Option<&Version> {
+        let name = normalize(name);
+        self.iter()
+            .find(|(other, _)| normalize(other) == name)
+            .map(|(_, version)| version)
+    }
+
+    /// Updates a requirement with a new version, if it's a workspace package
+    /// and has a version specifier, retaining extras and environment markers.
+    fn update_requirement(&self, spec: &str) -> Option<String> {
+        let (body, markers) = match spec.split_once(';') {
+            Some((body, markers)) => (body, Some(markers)),
+            None => (spec, None),
+        };
+
+        // Split off name and extras, which are retained verbatim
+        let (name, rest) = requirement(body);
+        let version = self.get_normalized(name)?;
+        let specifier = match rest.trim_start().strip_prefix('[') {
+            Some(rest) => rest.split_once(']')?.1,
+            None => rest,
+        };
+
+        // Assemble requirement with updated version specifier
+        let head = &body[..body.len() - specifier.len()];
+        let specifier = update_specifier(specifier, version)?;
+        let markers = markers.map(|markers| format!(";{markers}"));
+        Some(format!("{head}{specifier}{}", markers.unwrap_or_default()))
+    }
+
+    /// Updates a dependency with a new version.
+    #[allow(clippy::unused_self)]
+    fn update_dependency(&self, item: &mut Item, version: &Version) {
+        // Update simple version string: `foo = "^1.0.0"`
+        if item.is_str() {
+            *item = value(format!("^{version}"));
+
+        // Update inline table: `foo = { version = "^1.0.0" }`
+        } else if let Some(table) = item.as_table_like_mut() {
+            if table.contains_key("version") {
+                table.insert("version", value(format!("^{version}")));
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Normalizes a package name, as mandated by PEP 503, i.e., lowercases it,
+/// and replaces all runs of `-`, `_` and `.` with a single `-`.
+fn normalize(name: &str) -> String {
+    let mut value = String::with_capacity(name.len());
+    for part in name.split(['-', '_', '.']).filter(|part| !part.is_empty()) {
+        if !value.is_empty() {
+            value.push('-');
+        }
+        value.push_str(&part.to_lowercase());
+    }
+
+    // Return normalized name
+    value
+}
+
+/// Updates the version literals of all clauses of a version specifier that
+/// define a lower bound or pin, i.e., `>=`, `==`, `===` and `~=`, retaining
+/// all other clauses, e.g., upper bounds, as well as whitespace.
+///
+/// Wildcards and compatible release clauses retain the number of components
+/// of the literal, since it defines their semantics, e.g., `~=1.0` becomes
+/// `~=1.2` for `1.2.3`. If no clause was updated, [`None`] is returned.
+fn update_specifier(specifier: &str, version: &Version) -> Option<String> {
+    let mut updated = false;
+    let clauses = specifier.split(',').map(|clause| {
+        let trimmed = clause.trim_start_matches(|char: char| {
+            char.is_whitespace() || char == '('
+        });
+
+        // Determine operator, and skip clauses that don't need an update
+        let Some(operator) = OPERATORS
+            .into_iter()
+            .find(|operator| trimmed.starts_with(operator))
+            .filter(|&operator| matches!(operator, ">=" | "==" | "===" | "~="))
+        else {
+            return clause.to_string();
+        };
+
+        // Determine version literal and its position within the clause
+        let rest = &trimmed[operator.len()..];
+        let start = clause.len() - rest.trim_start().len();
+        let literal = rest
+            .trim_start()
+            .split(|char: char| char.is_whitespace() || char == ')')
+            .next()
+            .unwrap_or_default();
+
+        // Replace version literal, retaining wildcards and precision
+        let value = match literal.strip_suffix(".*") {
+            Some(prefix) => format!("{}.*", truncate(version, prefix)),
+            None if operator == "~=" => truncate(version, literal),
+            None => version.to_string(),
+        };
+        updated = true;
+        let end = start + literal.len();
+        format!("{}{value}{}", &clause[..start], &clause[end..])
+    });
+
+    // Collect clauses, and return specifier only if it was updated
+    let specifier = clauses.collect::<Vec<_>>().join(",");
+    updated.then_some(specifier)
+}
+
+/// Truncates a version to the number of release components of the literal,
+/// returning the full version if it has three or more components.
+fn truncate(version: &Version, literal: &str) -> String {
+    match literal.split('.').count() {
+        1 => version.major.to_string(),
+        2 => format!("{}.{}", version.major, version.minor),
+        _ => version.to_string(),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod normalize {
+        use crate::project::manifest::python::versions::normalize;
+
+        #[test]
+        fn handles_separators_and_casing() {
+            assert_eq!(normalize("Foo_Bar"), "foo-bar");
+            assert_eq!(normalize("foo.bar--baz"), "foo-bar-baz");
+            assert_eq!(normalize("foo"), "foo");
+        }
+    }
+
+    mod update_specifier {
+        use semver::Version;
+
+        use crate::project::manifest::python::versions::update_specifier;
+
+        #[test]
+        fn handles_lower_bounds_and_pins() {
+            let version = Version::new(1, 2, 3);
+            for (specifier, expected) in [
+                (">=1.0.0", ">=1.2.3"),
+                ("==1.0.0", "==1.2.3"),
+                (">=1.0.0,<2", ">=1.2.3,<2"),
+                (" >= 1.0.0, < 2.0", " >= 1.2.3, < 2.0"),
+                ("(>=1.0.0)", "(>=1.2.3)"),
+                ("~=1.0", "~=1.2"),
+                ("~=1.0.0", "~=1.2.3"),
+                ("==1.*", "==1.*"),
+                ("==1.0.*", "==1.2.*"),
+            ] {
+                let specifier = update_specifier(specifier, &version);
+                assert_eq!(specifier.as_deref(), Some(expected));
+            }
+        }
+
+        #[test]
+        fn handles_other_clauses() {
+            let version = Version::new(1, 2, 3);
+            assert_eq!(update_specifier("", &version), None);
+            assert_eq!(update_specifier("<2", &version), None);
+            assert_eq!(update_specifier("!=1.1.0", &version), None);
+        }
+    }
+}
@@ -25,7 +25,10 @@
 
 //! Iterator over members of a project.
 
+use glob::{MatchOptions, Pattern};
+use std::collections::BTreeSet;
 use std::marker::PhantomData;
+use std::path::PathBuf;
 
 use super::error::Result;
 use super::manifest::Manifest;
@@ -67,18 +70,61 @@ where
     /// you want to include the root project, iterate over [`Project`] itself.
     #[allow(clippy::missing_panics_doc)]
     pub fn members(&self) -> Members<T> {
-        let root = self.path.parent().expect("invariant");
+        let root = self.directory();
         let file = self.path.file_name().expect("invariant");
 
-        // Create path iterator over members and initialize stack
+        // Create path iterator over members, excluding all paths that match
+        // any of the exclusions, and initialize stack
         let data = self.manifest.members();
         let iter = data.iter().map(|path| root.join(path));
+        let mut paths: Paths = iter.rev().collect();
+        let data = self.manifest.exclusions();
+        paths.exclude(data.iter().map(|path| root.join(path)));
         Members {
-            paths: vec![iter.rev().collect()],
+            paths: vec![paths],
             file: file.to_string_lossy().to_string(),
             marker: PhantomData,
         }
     }
+
+    /// Returns the paths of all members of the project in the given set of
+    /// directories, e.g., as obtained from the tree of a commit.
+    ///
+    /// In contrast to [`Project::members`], which expands member patterns on
+    /// disk, this method matches them against the given directories, which
+    /// must be absolute, and doesn't descend into nested members.
+    pub(crate) fn members_in(
+        &self, dirs: &BTreeSet<PathBuf>,
+    ) -> Result<Vec<PathBuf>> {
+        let root = self.directory();
+        let compile = |path: &String| {
+            Pattern::new(&root.join(path).to_string_lossy()).map_err(Into::into)
+        };
+
+        // Compile member patterns and exclusions, and propagate the error in
+        // case a pattern is invalid, as when expanding them on disk
+        let data = self.manifest.members();
+        let patterns = data.iter().map(compile).collect::<Result<Vec<_>>>()?;
+        let data = self.manifest.exclusions();
+        let exclusions =
+            data.iter().map(compile).collect::<Result<Vec<_>>>()?;
+
+        // Wildcards must not match path separators, as globs are expanded per
+        // path component on disk, so we require them to be matched literally
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        let iter = dirs.iter().filter(|dir| {
+            patterns
+                .iter()
+                .any(|pattern| pattern.matches_path_with(dir, options))
+                && !exclusions.iter().any(|pattern| pattern.matches_path(dir))
+        });
+
+        // Collect and return paths
+        Ok(iter.cloned().collect())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -25,7 +25,7 @@
 
 //! Iterator over resolved paths of globs.
 
-use glob::glob;
+use glob::{glob, Pattern};
 use std::path::PathBuf;
 
 use crate::project::Result;
@@ -35,12 +35,32 @@ use crate::project::Result;
 // ----------------------------------------------------------------------------
 
 /// Iterator over resolved paths of globs.
+#[allow(clippy::struct_field_names)]
 #[derive(Debug, Default)]
 pub struct Paths {
     /// Stack of patterns.
     patterns: Vec<PathBuf>,
     /// Stack of paths.
     paths: Vec<PathBuf>,
+    /// Patterns of excluded paths.
+    exclusions: Vec<PathBuf>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Paths {
+    /// Excludes all paths that match any of the given patterns.
+    ///
+    /// Like patterns, exclusions must be valid paths, and are matched against
+    /// the resolved paths, so it's recommended to use absolute paths as well.
+    pub fn exclude<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = PathBuf>,
+    {
+        self.exclusions.extend(iter);
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -63,15 +83,30 @@ impl Iterator for Paths {
             // Collect paths and propagate errors - note that we need to know
             // when an error occurs, so we don't just silence them
             let iter = paths.into_iter().map(|res| res.map_err(Into::into));
-            match iter.collect::<Result<Vec<_>>>() {
-                Ok(paths) => {
-                    // We must make sure that every path is a directory, as we
-                    // append the manifest file name later on
-                    let iter = paths.into_iter().filter(|path| path.is_dir());
-                    self.paths.extend(iter.rev());
-                }
+            let paths = match iter.collect::<Result<Vec<_>>>() {
+                Ok(paths) => paths,
+                Err(err) => return Some(Err(err)),
+            };
+
+            // Compile exclusions, and propagate the error in case a pattern
+            // is invalid, so mistakes in exclusions don't go unnoticed
+            let iter = self.exclusions.iter().map(|path| {
+                Pattern::new(&path.to_string_lossy()).map_err(Into::into)
+            });
+            let exclusions = match iter.collect::<Result<Vec<_>>>() {
+                Ok(exclusions) => exclusions,
                 Err(err) => return Some(Err(err)),
-            }
+            };
+
+            // We must make sure that every path is a directory, as we append
+            // the manifest file name later on, and isn't excluded
+            let iter = paths.into_iter().filter(|path| {
+                path.is_dir()
+                    && !exclusions
+                        .iter()
+                        .any(|pattern| pattern.matches_path(path))
+            });
+            self.paths.extend(iter.rev());
         }
 
         // Return next path
@@ -94,6 +129,7 @@ impl FromIterator<PathBuf> for Paths {
         Self {
             patterns: iter.into_iter().collect(),
             paths: Vec::new(),
+            exclusions: Vec::new(),
         }
     }
 }
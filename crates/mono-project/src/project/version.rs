@@ -29,4 +29,4 @@ mod ext;
 mod increment;
 
 pub use ext::VersionExt;
-pub use increment::Increment;
+pub use increment::{Increment, IncrementError};
@@ -38,12 +38,30 @@ pub trait VersionExt {
     /// Returns the next version after applying the given increment.
     fn bump(&self, increment: Increment) -> Version;
 
+    /// Returns the next pre-release version with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error`] if the identifier is invalid.
+    fn bump_pre(
+        &self, increment: Increment, identifier: &str,
+    ) -> Result<Version, Error>;
+
     /// Returns the minimum increment for the version.
     fn min_bump(&self) -> Option<Increment>;
 
     /// Returns the maximum increment for the version.
     fn max_bump(&self) -> Increment;
 
+    /// Returns whether the version is stable, i.e., `x.y.z` with `x >= 1`.
+    fn is_stable(&self) -> bool;
+
+    /// Returns whether the version has a pre-release identifier.
+    fn is_prerelease(&self) -> bool;
+
+    /// Returns whether the version is in the `0.y.z` range.
+    fn is_zero(&self) -> bool;
+
     /// Parses a version from a string, allowing for an optional `v` prefix.
     ///
     /// Internally, versions are represented without the `v` prefix, but when
@@ -116,6 +134,52 @@ impl VersionExt for Version {
         version
     }
 
+    /// Returns the next pre-release version with the given identifier.
+    ///
+    /// If the version is already a pre-release of the same series, e.g., the
+    /// version is `2.0.0-rc.1` and the identifier is `rc`, the counter is just
+    /// incremented, resulting in `2.0.0-rc.2`. If it's a pre-release with a
+    /// different identifier, the counter is reset, e.g., `2.0.0-alpha.3` turns
+    /// into `2.0.0-rc.1`. Otherwise, the increment is applied to the version,
+    /// before the identifier is appended, e.g., `1.2.3` turns into `2.0.0-rc.1`
+    /// for a major increment. This is also the case if the identifier sorts
+    /// below the current one, so a new series is started, e.g., `2.0.0-rc.1`
+    /// turns into `3.0.0-alpha.1`, as the version must never decrease. Build
+    /// metadata is always reset.
+    fn bump_pre(
+        &self, increment: Increment, identifier: &str,
+    ) -> Result<Version, Error> {
+        let mut version = self.clone();
+
+        // Determine counter, which is incremented for the same series
+        let mut counter = 1;
+        if self.is_prerelease() {
+            if let Some(current) = self
+                .pre
+                .strip_prefix(identifier)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .and_then(|rest| rest.parse::<u64>().ok())
+            {
+                counter = current.saturating_add(1);
+            }
+        } else {
+            version = self.bump(increment);
+        }
+
+        // Set pre-release identifier and reset build metadata
+        version.pre = Prerelease::new(&format!("{identifier}.{counter}"))?;
+        version.build = BuildMetadata::EMPTY;
+
+        // Start a new series, if the identifier sorts below the current one
+        if version <= *self {
+            version = self.bump(increment);
+            version.pre = Prerelease::new(&format!("{identifier}.1"))?;
+        }
+
+        // Return next pre-release version
+        Ok(version)
+    }
+
     /// Returns the minimum increment for the version.
     fn min_bump(&self) -> Option<Increment> {
         if let (0, 0) = (self.major, self.minor) {
@@ -133,4 +197,82 @@ impl VersionExt for Version {
             (_, _) => Increment::Major,
         }
     }
+
+    /// Returns whether the version is stable, i.e., `x.y.z` with `x >= 1`.
+    #[inline]
+    fn is_stable(&self) -> bool {
+        self.major >= 1 && self.pre.is_empty()
+    }
+
+    /// Returns whether the version has a pre-release identifier.
+    #[inline]
+    fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    /// Returns whether the version is in the `0.y.z` range.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.major == 0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod bump_pre {
+        use semver::{Error, Version};
+
+        use crate::project::version::{Increment, VersionExt};
+
+        #[test]
+        fn handles_stable() -> Result<(), Error> {
+            let version = Version::new(1, 2, 3);
+            assert_eq!(
+                version.bump_pre(Increment::Major, "rc")?,
+                Version::parse("2.0.0-rc.1")?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_same_series() -> Result<(), Error> {
+            let version = Version::parse("2.0.0-rc.1")?;
+            assert_eq!(
+                version.bump_pre(Increment::Major, "rc")?,
+                Version::parse("2.0.0-rc.2")?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_other_series() -> Result<(), Error> {
+            let version = Version::parse("2.0.0-alpha.3")?;
+            assert_eq!(
+                version.bump_pre(Increment::Patch, "rc")?,
+                Version::parse("2.0.0-rc.1")?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_lower_series() -> Result<(), Error> {
+            let version = Version::parse("2.0.0-rc.1")?;
+            assert_eq!(
+                version.bump_pre(Increment::Major, "alpha")?,
+                Version::parse("3.0.0-alpha.1")?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_identifier() {
+            let version = Version::new(1, 0, 0);
+            assert!(version.bump_pre(Increment::Patch, "r c").is_err());
+        }
+    }
 }
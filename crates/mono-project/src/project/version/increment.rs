@@ -25,14 +25,24 @@
 
 //! Version increment.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
 // ----------------------------------------------------------------------------
 // Enums
 // ----------------------------------------------------------------------------
 
 /// Version increment.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Increments are ordered by significance, i.e., `Patch < Minor < Major`, so
+/// the highest increment can be determined with [`Ord::max`]. Note that this
+/// relies on the order of variants, which must therefore be retained.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum Increment {
     /// Patch increment.
     Patch,
@@ -42,17 +52,136 @@ pub enum Increment {
     Major,
 }
 
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Version increment error.
+#[derive(Debug, Error)]
+#[error("invalid increment: {0}")]
+pub struct IncrementError(String);
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Increment {
+    /// Returns the name of the increment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mono_project::version::Increment;
+    ///
+    /// // Obtain name of increment
+    /// assert_eq!(Increment::Major.display_name(), "major");
+    /// ```
+    #[must_use]
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Increment::Patch => "patch",
+            Increment::Minor => "minor",
+            Increment::Major => "major",
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
 
+impl FromStr for Increment {
+    type Err = IncrementError;
+
+    /// Attempts to create an increment from a string.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`IncrementError`], if the string does not denote
+    /// a valid [`Increment`] variant, i.e., `major`, `minor` or `patch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_project::version::Increment;
+    ///
+    /// // Create increment from string
+    /// let increment: Increment = "minor".parse()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "patch" => Ok(Increment::Patch),
+            "minor" => Ok(Increment::Minor),
+            "major" => Ok(Increment::Major),
+            _ => Err(IncrementError(value.to_string())),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 impl fmt::Display for Increment {
     /// Formats the increment for display.
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Increment::Patch => f.write_str("patch"),
-            Increment::Minor => f.write_str("minor"),
-            Increment::Major => f.write_str("major"),
+        f.write_str(self.display_name())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_str {
+        use std::str::FromStr;
+
+        use crate::project::version::{Increment, IncrementError};
+
+        #[test]
+        fn handles_valid_variants() -> Result<(), IncrementError> {
+            for increment in
+                [Increment::Patch, Increment::Minor, Increment::Major]
+            {
+                let name = increment.display_name();
+                assert_eq!(Increment::from_str(name)?, increment);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_variant() {
+            for value in ["Major", "micro", ""] {
+                assert!(Increment::from_str(value).is_err());
+            }
+        }
+    }
+
+    mod cmp {
+        use std::cmp::Ordering;
+
+        use crate::project::version::Increment;
+
+        #[test]
+        fn handles_all_pairs() {
+            let values = [Increment::Patch, Increment::Minor, Increment::Major];
+            for (i, a) in values.iter().enumerate() {
+                for (j, b) in values.iter().enumerate() {
+                    assert_eq!(a.cmp(b), i.cmp(&j));
+                    assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)));
+                }
+            }
+            assert_eq!(Increment::Patch.cmp(&Increment::Major), Ordering::Less);
+            assert_eq!(
+                Increment::Minor.max(Increment::Patch),
+                Increment::Minor
+            );
         }
     }
 }
@@ -25,10 +25,14 @@
 
 //! Workspace.
 
+use semver::Version;
 use std::collections::btree_map::Values;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
 use std::path::{Path, PathBuf};
 
+use mono_repository::{Commit, Repository};
+
 use super::error::Result;
 use super::manifest::Manifest;
 use super::Project;
@@ -85,26 +89,78 @@ where
         let project = Project::<T>::read(path.as_ref())?;
 
         // Extract root path of workspace, so we can make paths relative when
-        // necessary (e.g. for scopes), and create iterator over projects
-        let root = project.path.parent().expect("invariant").to_path_buf();
-        let iter = project.into_iter().map(|res| {
-            res.map(|package| {
-                let base = package.path.parent().expect("invariant");
-                (base.to_path_buf(), package)
+        // necessary (e.g. for scopes), and collect projects
+        let root = project.directory().to_path_buf();
+        let projects = project.into_iter().collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_projects(root, projects))
+    }
+
+    /// Attempts to read a workspace from the given path in the given commit.
+    ///
+    /// The path must point to the manifest, relative to the root of the given
+    /// repository. Manifests are read from the git object store, not from the
+    /// working directory, so no checkout is necessary. Project paths are
+    /// reported as if the commit was checked out in the working directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Repository`][], if the files of the commit
+    /// could not be read, or [`Error::Io`][], if the workspace could not be
+    /// read from them.
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    /// [`Error::Repository`]: crate::project::Error::Repository
+    #[allow(clippy::missing_panics_doc)]
+    pub fn read_at_commit<P>(
+        repository: &Repository, commit: &Commit<'_>, path: P,
+    ) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let base = repository.path().canonicalize()?;
+
+        // Collect all files of the commit, as well as all directories that
+        // contain them, so member patterns can be matched against them
+        let iter = repository.files_at_commit(commit)?.into_iter();
+        let files = iter.map(|file| base.join(file)).collect::<BTreeSet<_>>();
+        let iter = files.iter().flat_map(|file| file.ancestors().skip(1));
+        let dirs = iter
+            .filter(|dir| dir.starts_with(&base))
+            .map(Path::to_path_buf)
+            .collect::<BTreeSet<_>>();
+
+        // Read files from the commit's tree, and treat all files that aren't
+        // part of the commit as non-existent, e.g., outside the repository
+        let mut read = |path: &Path| {
+            let Some(file) = files.get(path) else {
+                return Ok(None);
+            };
+            let file = file.strip_prefix(&base).expect("invariant");
+            let content = repository.file_at_commit(commit, file)?;
+            String::from_utf8(content).map(Some).map_err(|err| {
+                io::Error::new(io::ErrorKind::InvalidData, err).into()
             })
-        });
+        };
 
-        // Collect projects and extract packages, so we can map package names
-        // to their paths in order to resolve projects by package name
-        let projects = iter.collect::<Result<BTreeMap<_, _>>>()?;
-        let iter = projects.iter().filter_map(|(path, project)| {
-            let opt = project.manifest.name();
-            opt.map(|name| (name.to_string(), path.clone()))
-        });
+        // Read root project, and then all members recursively, which are read
+        // from manifest files of the same name as the root project's
+        let path = base.join(path);
+        let manifest = T::read_with(&path, &mut read)?;
+        let mut stack = vec![Project { path, manifest }];
+        let mut projects = Vec::new();
+        while let Some(project) = stack.pop() {
+            let file = project.path.file_name().expect("invariant");
+            for dir in project.members_in(&dirs)? {
+                let path = dir.join(file);
+                let manifest = T::read_with(&path, &mut read)?;
+                stack.push(Project { path, manifest });
+            }
+            projects.push(project);
+        }
 
-        // Collect packages and return workspace
-        let packages = iter.collect::<BTreeMap<_, _>>();
-        Ok(Self { path: root, projects, packages })
+        // Extract root path of workspace, and return workspace
+        let root = projects[0].directory().to_path_buf();
+        Ok(Self::from_projects(root, projects))
     }
 
     /// Attempts to resolve a workspace at the given path.
@@ -122,6 +178,21 @@ where
         Self::read(T::resolve(path.as_ref())?)
     }
 
+    /// Attempts to reload the workspace from disk.
+    ///
+    /// This is useful after manifests were changed, e.g., by a version bump,
+    /// as the workspace doesn't track changes of the files it was read from.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][], if the workspace could not be read.
+    ///
+    /// [`Error::Io`]: crate::project::Error::Io
+    #[inline]
+    pub fn reload(&self) -> Result<Self> {
+        Self::resolve(&self.path)
+    }
+
     /// Returns a reference to the project with the given name.
     #[inline]
     #[must_use]
@@ -132,11 +203,106 @@ where
         self.projects.get(self.packages.get(name.as_ref())?)
     }
 
+    /// Returns whether the workspace contains a package with the given name.
+    #[inline]
+    #[must_use]
+    pub fn contains<N>(&self, name: N) -> bool
+    where
+        N: AsRef<str>,
+    {
+        self.packages.contains_key(name.as_ref())
+    }
+
+    /// Returns the number of packages in the workspace.
+    #[inline]
+    #[must_use]
+    pub fn packages_count(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns whether the workspace contains exactly one package, which is
+    /// the case for repositories that aren't actually monorepos.
+    #[inline]
+    #[must_use]
+    pub fn is_single_package(&self) -> bool {
+        self.packages.len() == 1
+    }
+
+    /// Returns the version of the package with the given name.
+    #[inline]
+    #[must_use]
+    pub fn package_version<N>(&self, name: N) -> Option<&Version>
+    where
+        N: AsRef<str>,
+    {
+        self.get(name).and_then(Project::version)
+    }
+
+    /// Returns whether all packages share the same version, as is the case
+    /// in workspaces that follow a lockstep versioning strategy.
+    #[must_use]
+    pub fn is_uniform_version(&self) -> bool {
+        let mut iter = self.versions_map().into_values();
+        iter.next()
+            .is_none_or(|version| iter.all(|next| next == version))
+    }
+
+    /// Returns the current versions of all packages, keyed by name.
+    #[must_use]
+    pub fn versions_map(&self) -> BTreeMap<&str, &Version> {
+        let iter = self.packages.keys().filter_map(|name| {
+            let version = self.package_version(name)?;
+            Some((name.as_str(), version))
+        });
+        iter.collect()
+    }
+
+    /// Creates an iterator over all private packages, which must be skipped
+    /// when publishing packages.
+    pub fn private_packages(&self) -> impl Iterator<Item = &Project<T>> {
+        self.packages
+            .values()
+            .filter_map(|path| self.projects.get(path))
+            .filter(|project| project.is_private())
+    }
+
     /// Creates an iterator over the workspace.
     #[inline]
     pub fn iter(&self) -> Values<'_, PathBuf, Project<T>> {
         self.into_iter()
     }
+
+    /// Creates a workspace at the given root path from the given projects.
+    fn from_projects(root: PathBuf, projects: Vec<Project<T>>) -> Self {
+        let iter = projects.into_iter().map(|project| {
+            let base = project.directory();
+            (base.to_path_buf(), project)
+        });
+
+        // Collect projects and extract packages, so we can map package names
+        // to their paths in order to resolve projects by package name
+        let projects = iter.collect::<BTreeMap<_, _>>();
+        let iter = projects.iter().filter_map(|(path, project)| {
+            let opt = project.manifest.name();
+            opt.map(|name| (name.to_string(), path.clone()))
+        });
+
+        // Collect packages and return workspace
+        let packages = iter.collect::<BTreeMap<_, _>>();
+        Self { path: root, projects, packages }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl<T> Workspace<T>
+where
+    T: Manifest,
+{
+    /// Returns a reference to the workspace path.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -156,3 +322,96 @@ where
         self.projects.values()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod read_at_commit {
+        use mono_repository::testing::Fixture;
+        use semver::Version;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::manifest::node::Node;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_inherited_version() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.write(
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"crates/*\"]\n\
+                 [workspace.package]\nversion = \"1.0.0\"\n",
+            )?;
+            fixture.write(
+                "crates/a/Cargo.toml",
+                "[package]\nname = \"a\"\nversion.workspace = true\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+
+            // Change version in working directory, which must not affect the
+            // workspace as read from the commit
+            fixture.write(
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"crates/*\"]\n\
+                 [workspace.package]\nversion = \"2.0.0\"\n",
+            )?;
+
+            // Read workspace from commit, and check version and paths
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            let workspace = Workspace::<Cargo>::read_at_commit(
+                &repository,
+                &commit,
+                "Cargo.toml",
+            )?;
+            let versions = workspace.versions_map();
+            assert_eq!(versions.get("a"), Some(&&Version::new(1, 0, 0)));
+            let root = fixture.path().canonicalize()?;
+            assert_eq!(workspace.path(), root);
+            let project = workspace.get("a").expect("invariant");
+            assert_eq!(project.directory(), root.join("crates/a"));
+            Ok(())
+        }
+
+        #[test]
+        fn handles_pnpm_workspace_with_exclusions() -> Result {
+            let fixture = Fixture::new()?;
+            for (path, name) in [
+                ("", "root"),
+                ("packages/a", "a"),
+                ("packages/a/nested", "nested"),
+                ("packages/internal", "internal"),
+            ] {
+                fixture.write(
+                    format!("{path}/package.json").trim_start_matches('/'),
+                    format!(r#"{{ "name": "{name}", "version": "1.0.0" }}"#),
+                )?;
+            }
+            fixture.write(
+                "pnpm-workspace.yaml",
+                "packages:\n  - packages/*\n  - '!packages/internal'\n",
+            )?;
+            fixture.commit("chore: initial commit")?;
+
+            // Read workspace from commit, and check that neither nested nor
+            // excluded directories are considered members
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            let workspace = Workspace::<Node>::read_at_commit(
+                &repository,
+                &commit,
+                "package.json",
+            )?;
+            assert_eq!(
+                workspace.versions_map().into_keys().collect::<Vec<_>>(),
+                ["a", "root"]
+            );
+            Ok(())
+        }
+    }
+}
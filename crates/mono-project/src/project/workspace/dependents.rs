@@ -30,7 +30,7 @@ use zrx::graph::traversal::IntoIter;
 use zrx::graph::Graph;
 
 use crate::project::manifest::Manifest;
-use crate::project::{Project, Result};
+use crate::project::{Error, Project, Result};
 
 use super::Workspace;
 
@@ -67,9 +67,12 @@ where
     ///
     /// # Errors
     ///
-    /// This method returns [`Error::Graph`][] if the graph could not be
-    /// constructed, which should practically never happen.
+    /// This method returns [`Error::Cycle`][] if packages depend on each other
+    /// in a circular way, which Cargo forbids, but other workspaces might have,
+    /// and [`Error::Graph`][] if the graph could not be constructed, which
+    /// should practically never happen.
     ///
+    /// [`Error::Cycle`]: crate::project::Error::Cycle
     /// [`Error::Graph`]: crate::project::Error::Graph
     pub fn dependents(&self) -> Result<Dependents<'_, T>> {
         let mut builder = Graph::builder();
@@ -100,6 +103,15 @@ where
             }
         }
 
+        // Ensure there are no circular dependencies, as a topological traversal
+        // of the graph would otherwise silently omit the affected packages
+        if let Some(cycle) = cycle(builder.nodes().len(), &edges) {
+            let iter = cycle.into_iter().filter_map(|n| {
+                builder.nodes()[n].manifest.name().map(ToString::to_string)
+            });
+            return Err(Error::Cycle(iter.collect()));
+        }
+
         // Create links between projects and their dependencies by adding all
         // collected edges to the graph. Note that links are inverted, so that
         // they point from dependencies to dependents, allowing for topological
@@ -111,6 +123,51 @@ where
         // Create and return dependents
         Ok(Dependents { graph: builder.build() })
     }
+
+    /// Returns the dependency graph of a workspace in Graphviz DOT format.
+    ///
+    /// Nodes are labelled with package names and versions, and edges point
+    /// from packages to their dependencies, so the output can be rendered
+    /// with `dot -Tsvg` for documentation or debugging purposes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Workspace::dependents`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn dependency_graph_dot(&self) -> Result<String> {
+        let dependents = self.dependents()?;
+        let graph = &dependents.graph;
+
+        // Add all packages as nodes in topological order, labelled with their
+        // names and versions, separated by a line break
+        let mut lines = vec![String::from("digraph {")];
+        for node in &dependents {
+            let project = graph[node];
+            let name = escape(project.name().expect("invariant"));
+            let version = project.version().expect("invariant");
+            lines.push(format!(
+                "    \"{name}\" [label=\"{name}\\n{version}\"];"
+            ));
+        }
+
+        // Add edges from packages to their dependencies, which are inverted in
+        // the graph, so we obtain them from the incoming adjacency list
+        let incoming = graph.topology().incoming();
+        for node in &dependents {
+            let name = escape(graph[node].name().expect("invariant"));
+            for &dependency in &incoming[node] {
+                let dependency =
+                    escape(graph[dependency].name().expect("invariant"));
+                lines.push(format!(
+                    "    \"{name}\" -> \"{dependency}\" [label=\"depends on\"];"
+                ));
+            }
+        }
+
+        // Close graph and return it
+        lines.push(String::from("}"));
+        Ok(lines.join("\n"))
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -170,3 +227,145 @@ where
         self.graph.traverse(self.graph.sources()).into_iter()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the nodes forming a cycle, if any, in the order they're linked.
+///
+/// Edges point from projects to their dependencies. We perform a depth-first
+/// search, tracking the current path, so when we reach a node on the path, the
+/// nodes from there on form the cycle.
+fn cycle(count: usize, edges: &[(usize, usize)]) -> Option<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); count];
+    for &(n, m) in edges {
+        adjacency[n].push(m);
+    }
+
+    // Visit all nodes, as the graph might consist of multiple components
+    let mut visited = vec![false; count];
+    let mut path = Vec::new();
+    (0..count).find_map(|n| visit(n, &adjacency, &mut visited, &mut path))
+}
+
+/// Visits the given node and its dependencies, returning a cycle, if any.
+fn visit(
+    n: usize, adjacency: &[Vec<usize>], visited: &mut [bool],
+    path: &mut Vec<usize>,
+) -> Option<Vec<usize>> {
+    if let Some(index) = path.iter().position(|&node| node == n) {
+        return Some(path[index..].to_vec());
+    }
+    if visited[n] {
+        return None;
+    }
+
+    // Descend into dependencies, and backtrack if no cycle was found
+    visited[n] = true;
+    path.push(n);
+    for &m in &adjacency[n] {
+        if let Some(cycle) = visit(m, adjacency, visited, path) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    None
+}
+
+/// Escapes the given value for use in a quoted string in Graphviz DOT format,
+/// which only requires escaping of double quotes and backslashes.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        if matches!(char, '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+
+    // Return escaped value
+    escaped
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod cycle {
+        use crate::project::workspace::dependents::cycle;
+
+        #[test]
+        fn handles_acyclic() {
+            let edges = [(0, 1), (1, 2), (0, 2)];
+            assert_eq!(cycle(3, &edges), None);
+        }
+
+        #[test]
+        fn handles_cycle() {
+            let edges = [(0, 1), (1, 2), (2, 1)];
+            assert_eq!(cycle(3, &edges), Some(vec![1, 2]));
+        }
+    }
+
+    mod dependency_graph_dot {
+        use std::fs;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::python::Python;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_dependencies() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                ("", "[tool.mono]\nmembers = [\"packages/*\"]\n"),
+                (
+                    "packages/a",
+                    "[project]\nname = \"a\"\nversion = \"1.0.0\"\n\
+                     dependencies = [\"b>=1.0.0\"]\n",
+                ),
+                (
+                    "packages/b",
+                    "[project]\nname = \"b\"\nversion = \"1.0.0\"\n",
+                ),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(
+                    dir.path().join(path).join("pyproject.toml"),
+                    content,
+                )?;
+            }
+
+            // Resolve workspace, and check dependency graph in DOT format
+            let workspace = Workspace::<Python>::resolve(dir.path())?;
+            assert_eq!(
+                workspace.dependency_graph_dot()?,
+                "digraph {\n    \
+                 \"b\" [label=\"b\\n1.0.0\"];\n    \
+                 \"a\" [label=\"a\\n1.0.0\"];\n    \
+                 \"a\" -> \"b\" [label=\"depends on\"];\n\
+                 }"
+            );
+            Ok(())
+        }
+    }
+
+    mod escape {
+        use crate::project::workspace::dependents::escape;
+
+        #[test]
+        fn handles_quotes_and_backslashes() {
+            assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+        }
+
+        #[test]
+        fn handles_other_characters() {
+            assert_eq!(escape("@scope/pkg-é"), "@scope/pkg-é");
+        }
+    }
+}
@@ -29,6 +29,7 @@ use std::collections::btree_map::Iter;
 use std::path::PathBuf;
 
 use crate::project::manifest::Manifest;
+use crate::project::Result;
 
 use super::Workspace;
 
@@ -71,6 +72,32 @@ where
             iter: self.packages.iter(),
         }
     }
+
+    /// Creates an iterator over the packages in the workspace in topological
+    /// order, i.e., dependencies are emitted before their dependents.
+    ///
+    /// Unlike [`Workspace::packages`], which emits packages in alphabetical
+    /// order, this is the natural order for build pipelines. Packages are
+    /// represented in the same way, i.e., as a tuple of path and name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`Workspace::dependents`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn packages_topological(
+        &self,
+    ) -> Result<impl Iterator<Item = (PathBuf, String)>> {
+        let dependents = self.dependents()?;
+        let iter = dependents.iter().map(|node| {
+            let name = dependents[node].name().expect("invariant");
+            let path = self.packages.get(name).expect("invariant");
+            let path = path.strip_prefix(&self.path).expect("invariant");
+            (path.to_path_buf(), name.to_string())
+        });
+
+        // Collect packages, since the dependents are dropped when returning
+        Ok(iter.collect::<Vec<_>>().into_iter())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -88,3 +115,55 @@ impl Iterator for Packages<'_> {
         })
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod packages_topological {
+        use std::fs;
+        use std::path::PathBuf;
+        use tempfile::TempDir;
+
+        use crate::project::manifest::cargo::Cargo;
+        use crate::project::workspace::Workspace;
+        use crate::project::Result;
+
+        #[test]
+        fn handles_dependencies() -> Result {
+            let dir = TempDir::new()?;
+            for (path, content) in [
+                ("", "[workspace]\nmembers = [\"crates/*\"]\n"),
+                (
+                    "crates/a",
+                    "[package]\nname = \"a\"\nversion = \"1.0.0\"\n\
+                     [dependencies]\nb = { path = \"../b\" }\n",
+                ),
+                (
+                    "crates/b",
+                    "[package]\nname = \"b\"\nversion = \"1.0.0\"\n\
+                     [dependencies]\nc = { path = \"../c\" }\n",
+                ),
+                ("crates/c", "[package]\nname = \"c\"\nversion = \"1.0.0\"\n"),
+            ] {
+                fs::create_dir_all(dir.path().join(path))?;
+                fs::write(dir.path().join(path).join("Cargo.toml"), content)?;
+            }
+
+            // Resolve workspace and check that dependencies come first
+            let workspace = Workspace::<Cargo>::resolve(dir.path())?;
+            assert_eq!(
+                workspace.packages_topological()?.collect::<Vec<_>>(),
+                [
+                    (PathBuf::from("crates/c"), String::from("c")),
+                    (PathBuf::from("crates/b"), String::from("b")),
+                    (PathBuf::from("crates/a"), String::from("a")),
+                ]
+            );
+            Ok(())
+        }
+    }
+}
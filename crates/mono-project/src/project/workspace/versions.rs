@@ -27,8 +27,8 @@
 
 use semver::Version;
 use std::collections::BTreeMap;
-use std::fs;
 use std::marker::PhantomData;
+use std::{cmp, fs};
 
 use crate::project::manifest::Manifest;
 use crate::project::version::{Increment, VersionExt};
@@ -51,6 +51,8 @@ use super::Workspace;
 pub struct Versions<'a, T> {
     /// Package versions.
     items: BTreeMap<&'a str, Version>,
+    /// Version inherited from the workspace.
+    inherited: Option<Version>,
     /// Type marker.
     marker: PhantomData<T>,
 }
@@ -66,7 +68,9 @@ where
     /// Applies the given version increments to all packages in the workspace.
     ///
     /// Note that this method consumes the workspace, since it doesn't update
-    /// its internal stte, but needs to be recreated.
+    /// its internal state, but returns the new versions of all packages that
+    /// were bumped, so manifests don't need to be read again. If `sync` is
+    /// set, lock files are synchronized after the update.
     ///
     /// # Errors
     ///
@@ -75,30 +79,124 @@ where
     ///
     /// [`Error:Io`]: crate::project::Error::Io
     /// [`Writer`]: crate::project::manifest::Writer
-    #[allow(clippy::missing_panics_doc)]
-    pub fn bump(self, increments: &[Option<Increment>]) -> Result {
+    #[inline]
+    pub fn bump(
+        self, increments: &[Option<Increment>], sync: bool,
+    ) -> Result<BTreeMap<String, Version>> {
+        self.bump_with(increments, sync, |version, increment| {
+            Ok(version.bump(increment))
+        })
+    }
+
+    /// Applies the given version increments to all packages in the workspace,
+    /// creating pre-releases with the given identifier, e.g., `2.0.0-rc.1`.
+    ///
+    /// Note that this method consumes the workspace, since it doesn't update
+    /// its internal state, but returns the new versions of all packages that
+    /// were bumped, so manifests don't need to be read again. If `sync` is
+    /// set, lock files are synchronized after the update.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`][] if the identifier is invalid,
+    /// [`Error:Io`][] if a manifest can't be written to disk, or any error as
+    /// encountered by the [`Writer`][] implementation.
+    ///
+    /// [`Error::Version`]: crate::project::Error::Version
+    /// [`Error:Io`]: crate::project::Error::Io
+    /// [`Writer`]: crate::project::manifest::Writer
+    #[inline]
+    pub fn bump_pre(
+        self, increments: &[Option<Increment>], identifier: &str, sync: bool,
+    ) -> Result<BTreeMap<String, Version>> {
+        self.bump_with(increments, sync, |version, increment| {
+            Ok(version.bump_pre(increment, identifier)?)
+        })
+    }
+
+    /// Sets the given version for all packages in the workspace that have a
+    /// version increment, e.g., when creating the initial release.
+    ///
+    /// Note that this method consumes the workspace, since it doesn't update
+    /// its internal state, but returns the new versions of all packages that
+    /// were bumped, so manifests don't need to be read again. If `sync` is
+    /// set, lock files are synchronized after the update.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error:Io`][] if a manifest can't be written to
+    /// disk, or any error as encountered by the [`Writer`][] implementation.
+    ///
+    /// [`Error:Io`]: crate::project::Error::Io
+    /// [`Writer`]: crate::project::manifest::Writer
+    #[inline]
+    pub fn bump_to(
+        self, increments: &[Option<Increment>], version: &Version, sync: bool,
+    ) -> Result<BTreeMap<String, Version>> {
+        self.bump_with(increments, sync, |_, _| Ok(version.clone()))
+    }
+
+    /// Applies the given version increments using the given function.
+    fn bump_with<F>(
+        self, increments: &[Option<Increment>], sync: bool, f: F,
+    ) -> Result<BTreeMap<String, Version>>
+    where
+        F: Fn(&Version, Increment) -> Result<Version>,
+    {
         let mut items = BTreeMap::new();
 
         // Compute new versions for all packages in workspace, and collect them
         // into a version set that we then pass to each manifest writer
+        let mut inherited = None;
         for (index, (name, path)) in self.packages.iter().enumerate() {
             if let Some(increment) = increments[index] {
                 let project = self.projects.get(path).expect("invariant");
-                let version = project.version().expect("invariant");
-                items.insert(name.as_str(), version.bump(increment));
+                let version =
+                    f(project.version().expect("invariant"), increment)?;
+
+                // Packages inheriting their version from the workspace share
+                // it, so we retain the highest version for the workspace
+                if project.manifest.inherits_version() {
+                    inherited = cmp::max(inherited, Some(version.clone()));
+                }
+                items.insert(name.as_str(), version);
+            }
+        }
+
+        // Packages inheriting their version from the workspace are bumped as
+        // one unit, so if any of them was bumped, all of them are set to the
+        // shared version before manifests and requirements are written
+        if let Some(version) = &inherited {
+            for (name, path) in &self.packages {
+                let project = self.projects.get(path).expect("invariant");
+                if project.manifest.inherits_version() {
+                    items.insert(name.as_str(), version.clone());
+                }
             }
         }
 
         // The type marker is only necessary to discern between implementations
         // of the trait for different manifest types
-        let versions = Versions { items, marker: PhantomData };
+        let versions = Versions {
+            items,
+            inherited,
+            marker: PhantomData,
+        };
         for project in &self {
             let content = fs::read_to_string(project.path())?;
             fs::write(project.path(), T::update(content, &versions)?)?;
         }
 
-        // Synchronize workspace manifest after update
-        T::sync(self.path)
+        // Synchronize workspace manifest after update, if desired
+        if sync {
+            T::sync(&self.path)?;
+        }
+
+        // Return new versions of all packages that were bumped
+        let iter = versions.items.into_iter();
+        Ok(iter
+            .map(|(name, version)| (name.to_string(), version))
+            .collect())
     }
 }
 
@@ -114,4 +212,17 @@ impl<T> Versions<'_, T> {
     {
         self.items.get(name.as_ref())
     }
+
+    /// Creates an iterator over all package names and versions.
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &Version)> {
+        self.items.iter().map(|(name, version)| (*name, version))
+    }
+
+    /// Returns the version inherited from the workspace, if any.
+    #[inline]
+    #[must_use]
+    pub fn inherited(&self) -> Option<&Version> {
+        self.inherited.as_ref()
+    }
 }
@@ -28,8 +28,12 @@
 #![allow(clippy::match_same_arms)]
 
 mod repository;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub use repository::commit::{self, Commit};
+pub use repository::forge::{self, Forge};
 pub use repository::id::{self, Id};
+pub use repository::stats::{self, DiffStats};
 pub use repository::versions::{self, Versions};
 pub use repository::{Error, Repository, Result};
@@ -25,17 +25,20 @@
 
 //! Repository.
 
-use std::fmt;
 use std::path::Path;
 use std::process::Command;
+use std::{fmt, io, result};
 
 pub mod commit;
 pub mod commits;
 mod error;
+pub mod forge;
 pub mod id;
+pub mod stats;
 pub mod versions;
 
 pub use error::{Error, Result};
+use id::Id;
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -101,29 +104,93 @@ impl Repository {
     /// Note that this method can't use the [`git2`] crate's `commit` logic, as
     /// this makes it impossible to sign commits using GPG. For this reason, we
     /// need to fallback to the `git` command line interface, committing the
-    /// changes the regular way.
+    /// changes the regular way. If signing is requested, the commit is signed
+    /// with the key configured in `user.signingKey`. If sign-off is requested,
+    /// a `Signed-off-by` trailer is added, as required by the DCO. Output of
+    /// `git` is written to standard error.
     ///
     /// # Errors
     ///
     /// This method returns [`Error::Git`] if the operation fails.
-    pub fn commit<M>(&self, message: M) -> Result
+    pub fn commit<M>(&self, message: M, sign: bool, signoff: bool) -> Result
     where
         M: AsRef<str>,
+    {
+        let mut command = Command::new("git");
+        command.current_dir(self.path()).args([
+            "commit",
+            "--cleanup=verbatim", // Preserve markdown formatting
+            "--no-verify",        // Don't run commit hooks
+            "--message",
+            message.as_ref(),
+        ]);
+        if signoff {
+            command.arg("--signoff"); // Add `Signed-off-by` trailer
+        }
+        if sign {
+            command.arg("--gpg-sign");
+        }
+
+        // Redirect the commit summary to standard error, so standard output
+        // is kept free for machine-readable output
+        let status = command.stdout(io::stderr()).status()?;
+
+        // Wrap non-zero exit status as error - switch to `ExitStatusError` when
+        // #84908 is stable – https://github.com/rust-lang/rust/issues/84908
+        if !status.success() {
+            return Err(Error::Status(status));
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Pushes the given branch or tag to the `origin` remote.
+    ///
+    /// Like [`Repository::commit`], this method falls back to the `git` command
+    /// line interface, so configured credential helpers are picked up.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Status`] if the operation fails.
+    pub fn push<B>(&self, branch: B) -> Result
+    where
+        B: AsRef<str>,
     {
         let status = Command::new("git")
             .current_dir(self.path())
-            .args([
-                "commit",
-                "--cleanup=verbatim", // Preserve markdown formatting
-                "--signoff",          // Add `Signed-off-by` trailer
-                "--no-verify",        // Don't run commit hooks
-                "--message",
-                message.as_ref(),
-            ])
+            .args(["push", "origin", branch.as_ref()])
             .status()?;
 
-        // Wrap non-zero exit status as error - switch to `ExitStatusError` when
-        // #84908 is stable – https://github.com/rust-lang/rust/issues/84908
+        // Wrap non-zero exit status as error
+        if !status.success() {
+            return Err(Error::Status(status));
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Fetches all branches and tags from the given remote.
+    ///
+    /// Like [`Repository::push`], this method falls back to the `git` command
+    /// line interface, so configured credential helpers are picked up. This
+    /// ensures that local tags are up-to-date before resolving versions.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Status`] if the operation fails.
+    pub fn fetch<R>(&self, remote: R) -> Result
+    where
+        R: AsRef<str>,
+    {
+        let status = Command::new("git")
+            .current_dir(self.path())
+            .args(["fetch", "--tags", remote.as_ref()])
+            .stdout(io::stderr())
+            .status()?;
+
+        // Wrap non-zero exit status as error
         if !status.success() {
             return Err(Error::Status(status));
         }
@@ -132,6 +199,109 @@ impl Repository {
         Ok(())
     }
 
+    /// Creates a tag with the given name at the current `HEAD`.
+    ///
+    /// If a message is given, an annotated tag is created, and a lightweight
+    /// tag otherwise. Like [`Repository::commit`], this method falls back to
+    /// the `git` command line interface, so tags can be signed using GPG. Note
+    /// that signed tags are always annotated, so a message should be given.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Status`] if the operation fails.
+    pub fn tag_create<N, M>(
+        &self, name: N, message: Option<M>, sign: bool,
+    ) -> Result
+    where
+        N: AsRef<str>,
+        M: AsRef<str>,
+    {
+        let mut command = Command::new("git");
+        command.current_dir(self.path()).arg("tag");
+        if sign {
+            command.arg("--sign");
+        } else if message.is_some() {
+            command.arg("--annotate");
+        }
+        if let Some(message) = message {
+            command.args(["--message", message.as_ref()]);
+        }
+
+        // Wrap non-zero exit status as error
+        let status = command.arg(name.as_ref()).status()?;
+        if !status.success() {
+            return Err(Error::Status(status));
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Returns the string value of the given Git configuration key.
+    ///
+    /// The configuration is read with the usual precedence, i.e., values set
+    /// for the repository override global and system values. If the key isn't
+    /// set, nothing is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the configuration can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::Repository;
+    ///
+    /// // Open repository and read configuration value
+    /// let repo = Repository::open(".")?;
+    /// let hooks = repo.config_get("core.hooksPath")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn config_get(&self, key: &str) -> Result<Option<String>> {
+        match self.inner.config()?.get_string(key) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns the boolean value of the given Git configuration key.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the configuration can't be read,
+    /// or the value is not a valid boolean.
+    pub fn config_get_bool(&self, key: &str) -> Result<Option<bool>> {
+        match self.inner.config()?.get_bool(key) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns whether signing commits and tags using GPG is configured.
+    ///
+    /// Signing is considered configured if either `user.signingKey` or the
+    /// `gpg.program` is set in the Git configuration, so we can catch setups
+    /// early that would otherwise fail or silently produce unsigned commits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the configuration can't be read.
+    pub fn is_signing_configured(&self) -> Result<bool> {
+        for key in ["user.signingKey", "gpg.program"] {
+            if self.config_get(key)?.is_some() {
+                return Ok(true);
+            }
+        }
+
+        // Signing is not configured
+        Ok(false)
+    }
+
     /// Creates a new branch with the given name from the current `HEAD`.
     ///
     /// # Errors
@@ -155,6 +325,140 @@ impl Repository {
         Ok(())
     }
 
+    /// Checks out the branch with the given name, discarding all changes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn checkout<N>(&self, name: N) -> Result
+    where
+        N: AsRef<str>,
+    {
+        let branch = self
+            .inner
+            .find_branch(name.as_ref(), git2::BranchType::Local)?;
+
+        // Set `HEAD` to branch and update working directory, forcefully
+        if let Some(name) = branch.get().name() {
+            self.inner.set_head(name)?;
+        }
+        let mut options = git2::build::CheckoutBuilder::new();
+        self.inner.checkout_head(Some(options.force()))?;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Checks out the given commit as a detached `HEAD`, discarding changes.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn checkout_detached<I>(&self, id: I) -> Result
+    where
+        I: Into<Id>,
+    {
+        self.inner.set_head_detached(*id.into())?;
+        let mut options = git2::build::CheckoutBuilder::new();
+        self.inner.checkout_head(Some(options.force()))?;
+        Ok(())
+    }
+
+    /// Resets `HEAD`, index and working directory to the given commit.
+    ///
+    /// This is equivalent to `git reset --hard`, so all uncommitted changes
+    /// to tracked files are discarded.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn reset_hard<I>(&self, id: I) -> Result
+    where
+        I: Into<Id>,
+    {
+        let object = self.inner.find_object(*id.into(), None)?;
+        self.inner.reset(&object, git2::ResetType::Hard, None)?;
+        Ok(())
+    }
+
+    /// Deletes the local branch with the given name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails, e.g., if the
+    /// branch is currently checked out.
+    pub fn branch_delete<N>(&self, name: N) -> Result
+    where
+        N: AsRef<str>,
+    {
+        let mut branch = self
+            .inner
+            .find_branch(name.as_ref(), git2::BranchType::Local)?;
+        branch.delete()?;
+        Ok(())
+    }
+
+    /// Deletes the local tag with the given name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails, e.g., if the
+    /// tag doesn't exist.
+    pub fn tag_delete<N>(&self, name: N) -> Result
+    where
+        N: AsRef<str>,
+    {
+        self.inner.tag_delete(name.as_ref())?;
+        Ok(())
+    }
+
+    /// Returns whether a local tag with the given name exists.
+    #[must_use]
+    pub fn tag_exists<N>(&self, name: N) -> bool
+    where
+        N: AsRef<str>,
+    {
+        let name = format!("refs/tags/{}", name.as_ref());
+        self.inner.find_reference(&name).is_ok()
+    }
+
+    /// Creates an iterator over the names of all local branches.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn branches(&self) -> Result<impl Iterator<Item = Result<String>>> {
+        let branches = self.inner.branches(Some(git2::BranchType::Local))?;
+        Ok(branches.filter_map(branch_name))
+    }
+
+    /// Creates an iterator over the names of all branches of the given remote.
+    ///
+    /// Names are returned without the remote prefix, e.g., `main` instead of
+    /// `origin/main`, and the symbolic `HEAD` of the remote is omitted.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn remote_branches<R>(
+        &self, remote: R,
+    ) -> Result<impl Iterator<Item = Result<String>>>
+    where
+        R: AsRef<str>,
+    {
+        let prefix = format!("{}/", remote.as_ref());
+        let branches = self.inner.branches(Some(git2::BranchType::Remote))?;
+        Ok(branches
+            .filter_map(branch_name)
+            .filter_map(move |res| match res {
+                Ok(name) => name
+                    .strip_prefix(&prefix)
+                    .filter(|&name| name != "HEAD")
+                    .map(|name| Ok(name.to_string())),
+                Err(err) => Some(Err(err)),
+            }))
+    }
+
     /// Returns whether there are no uncommitted or untracked changes.
     ///
     /// # Errors
@@ -209,17 +513,126 @@ impl Repository {
     /// # Ok(())
     /// # }
     /// ```
+    #[inline]
     pub fn on_default_branch(&self) -> Result<bool> {
-        let opt = self.inner.head()?;
-        Ok(opt
-            .shorthand()
-            .filter(|name| ["master", "main"].contains(name))
-            .is_some())
+        self.on_named_branch(&["main", "master"])
+    }
+
+    /// Returns whether the current branch is one of the given branches.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::Repository;
+    ///
+    /// // Find and open repository from current directory
+    /// let repo = Repository::open(".")?;
+    /// if !repo.on_named_branch(&["main", "trunk"])? {
+    ///     println!("Not on main or trunk");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_named_branch<S>(&self, names: &[S]) -> Result<bool>
+    where
+        S: AsRef<str>,
+    {
+        let opt = self.current_branch()?;
+        Ok(opt.is_some_and(|name| {
+            names.iter().any(|candidate| candidate.as_ref() == name)
+        }))
+    }
+
+    /// Returns the name of the current branch, or nothing if detached.
+    ///
+    /// The name is obtained from the symbolic target of `HEAD`, so this also
+    /// works for branches without commits, e.g., in new repositories.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::Repository;
+    ///
+    /// // Find and open repository from current directory
+    /// let repo = Repository::open(".")?;
+    /// if let Some(name) = repo.current_branch()? {
+    ///     println!("On branch {name}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn current_branch(&self) -> Result<Option<String>> {
+        let target = self.symbolic_ref("HEAD")?;
+        Ok(target.and_then(|target| {
+            let name = target.strip_prefix("refs/heads/")?;
+            Some(name.to_string())
+        }))
+    }
+
+    /// Returns the target of the symbolic reference with the given name.
+    ///
+    /// Symbolic references point to other references, e.g., `HEAD` points to
+    /// `refs/heads/main` when on a branch. If the reference isn't symbolic,
+    /// e.g., if `HEAD` is detached, nothing is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the reference doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::Repository;
+    ///
+    /// // Find and open repository from current directory
+    /// let repo = Repository::open(".")?;
+    /// if let Some(target) = repo.symbolic_ref("HEAD")? {
+    ///     println!("HEAD points to {target}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symbolic_ref(&self, name: &str) -> Result<Option<String>> {
+        let reference = self.inner.find_reference(name)?;
+        Ok(reference.symbolic_target().map(ToString::to_string))
+    }
+
+    /// Returns whether `HEAD` is detached, i.e., not pointing to a branch.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    #[inline]
+    pub fn is_detached_head(&self) -> Result<bool> {
+        Ok(self.inner.head_detached()?)
     }
 }
 
 #[allow(clippy::must_use_candidate)]
 impl Repository {
+    /// Returns whether the repository is a shallow clone.
+    ///
+    /// Shallow clones, as created by CI systems by default, only include part
+    /// of the history, so commits and versions might be missing.
+    #[inline]
+    pub fn is_shallow(&self) -> bool {
+        self.inner.is_shallow()
+    }
+
     /// Returns a reference to the repository path.
     #[allow(clippy::missing_panics_doc)]
     #[inline]
@@ -237,6 +650,90 @@ impl fmt::Debug for Repository {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Repository")
             .field("path", &self.path())
+            .field("branch", &self.current_branch().ok().flatten())
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the name of a branch, skipping branches with non-UTF-8 names.
+fn branch_name(
+    res: result::Result<(git2::Branch, git2::BranchType), git2::Error>,
+) -> Option<Result<String>> {
+    match res {
+        Ok((branch, _)) => match branch.name() {
+            Ok(name) => name.map(|name| Ok(name.to_string())),
+            Err(err) => Some(Err(err.into())),
+        },
+        Err(err) => Some(Err(err.into())),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod current_branch {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_branch_and_detached_head() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create an empty commit and check out a new branch
+            let id = fixture.commit("fix: summary")?;
+            fixture.branch("topic", &fixture.find_commit(id)?, false)?;
+            fixture.set_head("refs/heads/topic")?;
+
+            // Open repository and check current branch
+            let repository = fixture.open()?;
+            assert_eq!(repository.current_branch()?.as_deref(), Some("topic"));
+            assert!(!repository.is_detached_head()?);
+            assert!(!repository.on_default_branch()?);
+            assert!(repository.on_named_branch(&["main", "topic"])?);
+            assert_eq!(
+                repository.symbolic_ref("HEAD")?.as_deref(),
+                Some("refs/heads/topic")
+            );
+            let branches =
+                repository.branches()?.collect::<Result<Vec<_>>>()?;
+            assert!(branches.contains(&String::from("topic")));
+
+            // Detach head and check again
+            fixture.set_head_detached(id)?;
+            assert_eq!(repository.current_branch()?, None);
+            assert_eq!(repository.symbolic_ref("HEAD")?, None);
+            assert!(repository.is_detached_head()?);
+            Ok(())
+        }
+    }
+
+    mod tag_exists {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_created_and_deleted_tags() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create an empty commit and a lightweight tag pointing to it
+            let id = fixture.commit("fix: summary")?;
+            fixture.tag("v1.0.0", id)?;
+
+            // Open repository, check tag and delete it
+            let repository = fixture.open()?;
+            assert!(repository.tag_exists("v1.0.0"));
+            assert!(!repository.tag_exists("v2.0.0"));
+            repository.tag_delete("v1.0.0")?;
+            assert!(!repository.tag_exists("v1.0.0"));
+            Ok(())
+        }
+    }
+}
@@ -26,6 +26,7 @@
 //! Commit.
 
 use std::fmt;
+use std::path::{Path, PathBuf};
 
 use super::error::Result;
 use super::id::Id;
@@ -33,10 +34,12 @@ use super::Repository;
 
 mod delta;
 mod deltas;
+mod snapshot;
 mod trailers;
 
 pub use delta::Delta;
 pub use deltas::Deltas;
+pub use snapshot::CommitSnapshot;
 pub use trailers::Trailers;
 
 // ----------------------------------------------------------------------------
@@ -93,6 +96,120 @@ impl Repository {
             inner: object.peel_to_commit()?,
         })
     }
+
+    /// Attempts to find the merge base, i.e., common ancestor, of two commits.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails, or if the
+    /// commits don't have a common ancestor.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn merge_base<A, B>(&self, a: A, b: B) -> Result<Commit<'_>>
+    where
+        A: Into<Id>,
+        B: Into<Id>,
+    {
+        let id = self.inner.merge_base(*a.into(), *b.into())?;
+        self.get(id)
+    }
+
+    /// Attempts to find the merge base of the given commit and `HEAD`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails, or if the
+    /// commits don't have a common ancestor.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn merge_base_with_head<I>(&self, id: I) -> Result<Commit<'_>>
+    where
+        I: Into<Id>,
+    {
+        let head = self.inner.head()?.peel_to_commit()?.id();
+        self.merge_base(id, head)
+    }
+
+    /// Returns the content of the file at the given path in the given commit.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails, or if the
+    /// path doesn't exist or doesn't refer to a file in the commit's tree.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn file_at_commit<P>(
+        &self, commit: &Commit<'_>, path: P,
+    ) -> Result<Vec<u8>>
+    where
+        P: AsRef<Path>,
+    {
+        let entry = commit.inner.tree()?.get_path(path.as_ref())?;
+        let blob = entry.to_object(&self.inner)?.peel_to_blob()?;
+        Ok(blob.content().to_vec())
+    }
+
+    /// Returns the paths of all files in the given commit.
+    ///
+    /// Paths are relative to the root of the repository, and listed in the
+    /// order of the commit's tree, which is sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn files_at_commit(&self, commit: &Commit<'_>) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+
+        // Walk the commit's tree and collect the paths of all blobs, which
+        // are prefixed with the path of their parent tree
+        let tree = commit.inner.tree()?;
+        tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+            if entry.kind() == Some(git2::ObjectType::Blob) {
+                if let Some(name) = entry.name() {
+                    paths.push(Path::new(root).join(name));
+                }
+            }
+            git2::TreeWalkResult::Ok
+        })?;
+
+        // Return paths
+        Ok(paths)
+    }
+
+    /// Returns the note attached to the given commit in the given namespace.
+    ///
+    /// The namespace can be given as a full reference, e.g., `refs/notes/ci`,
+    /// or as a short name, e.g., `ci`, which is prefixed with `refs/notes/`.
+    /// Git's default namespace is `commits`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails. If there's
+    /// no note attached to the commit, [`None`] is returned.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn notes<R>(
+        &self, commit: &Commit<'_>, namespace: R,
+    ) -> Result<Option<String>>
+    where
+        R: AsRef<str>,
+    {
+        let namespace = namespace.as_ref();
+        let namespace = if namespace.starts_with("refs/") {
+            namespace.to_string()
+        } else {
+            format!("refs/notes/{namespace}")
+        };
+
+        // Find note, and return nothing if there is none
+        match self.inner.find_note(Some(&namespace), commit.inner.id()) {
+            Ok(note) => Ok(note.message().map(ToString::to_string)),
+            Err(err) if err.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -117,6 +234,53 @@ impl Commit<'_> {
     pub fn body(&self) -> Option<&str> {
         self.inner.body().filter(|body| !body.is_empty())
     }
+
+    /// Returns the commit message, consisting of summary and body.
+    ///
+    /// This is equivalent to the message as shown by `git log --format=%B`,
+    /// with summary and body separated by a blank line.
+    pub fn message(&self) -> String {
+        match self.body() {
+            Some(body) => format!("{}\n\n{body}", self.summary()),
+            None => self.summary().to_string(),
+        }
+    }
+
+    /// Returns the raw commit message, including all trailers.
+    #[allow(clippy::missing_panics_doc)]
+    #[inline]
+    pub fn message_raw(&self) -> &str {
+        self.inner.message().expect("invariant")
+    }
+
+    /// Returns the commit author name.
+    ///
+    /// Git doesn't enforce names to be valid UTF-8, which is why we need to
+    /// return an owned string, replacing invalid sequences if necessary.
+    #[inline]
+    pub fn author(&self) -> String {
+        let author = self.inner.author();
+        String::from_utf8_lossy(author.name_bytes()).into_owned()
+    }
+
+    /// Returns the commit author email.
+    #[inline]
+    pub fn author_email(&self) -> String {
+        let author = self.inner.author();
+        String::from_utf8_lossy(author.email_bytes()).into_owned()
+    }
+
+    /// Returns the commit timestamp in seconds since the Unix epoch.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.inner.time().seconds()
+    }
+
+    /// Returns whether the commit is a merge commit.
+    #[inline]
+    pub fn is_merge(&self) -> bool {
+        self.inner.parent_count() > 1
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -149,6 +313,8 @@ impl fmt::Debug for Commit<'_> {
             .field("id", &self.id())
             .field("summary", &self.summary())
             .field("body", &self.body())
+            .field("author", &self.author())
+            .field("timestamp", &self.timestamp())
             .finish()
     }
 }
@@ -175,3 +341,153 @@ pub fn trim_trailers(message: &str) -> Result<&str> {
         Ok(message)
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod accessors {
+        use crate::repository::Result;
+        use crate::testing::{Fixture, TIMESTAMP};
+
+        #[test]
+        fn handles_author_and_timestamp() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.commit("fix: summary")?;
+
+            // Open repository and check accessors of latest commit
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert_eq!(commit.author(), "Jane");
+            assert_eq!(commit.author_email(), "jane@example.com");
+            assert_eq!(commit.timestamp(), TIMESTAMP);
+            assert_eq!(commit.message(), "fix: summary");
+            assert_eq!(commit.message_raw(), "fix: summary");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_merge() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create two root commits, and merge them with a third commit
+            let a = fixture.commit_with(None, "fix: a", &[])?;
+            let b = fixture.commit_with(None, "fix: b", &[])?;
+            fixture.commit_with(Some("HEAD"), "Merge branch", &[a, b])?;
+
+            // Open repository and check latest commit and its first parent
+            let repository = fixture.open()?;
+            assert!(repository.find("HEAD")?.is_merge());
+            assert!(!repository.find("HEAD^1")?.is_merge());
+            Ok(())
+        }
+    }
+
+    mod merge_base {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_branches() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create a base commit, and two commits branching off from it
+            let base = fixture.commit("fix: base")?;
+            let a = fixture.commit_with(None, "fix: a", &[base])?;
+            let b = fixture.commit_with(None, "fix: b", &[base])?;
+            fixture.set_head_detached(b)?;
+
+            // Open repository and check merge base of both branches
+            let repository = fixture.open()?;
+            let commit = repository.merge_base(a, b)?;
+            assert_eq!(*commit.id(), base);
+            let commit = repository.merge_base_with_head(a)?;
+            assert_eq!(*commit.id(), base);
+            Ok(())
+        }
+    }
+
+    mod notes {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_namespaces() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create an empty commit, and attach a note in a custom namespace
+            let id = fixture.commit("fix: summary")?;
+            let signature = fixture.signature()?;
+            fixture.note(
+                &signature,
+                &signature,
+                Some("refs/notes/ci"),
+                id,
+                "passed",
+                false,
+            )?;
+
+            // Open repository and read notes of latest commit
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            let note = repository.notes(&commit, "ci")?;
+            assert_eq!(note.as_deref(), Some("passed"));
+            let note = repository.notes(&commit, "refs/notes/ci")?;
+            assert_eq!(note.as_deref(), Some("passed"));
+            assert_eq!(repository.notes(&commit, "commits")?, None);
+            Ok(())
+        }
+    }
+
+    mod file_at_commit {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_file() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create a commit with a single file in a nested directory
+            fixture.write("a/b.txt", "content")?;
+            fixture.commit("fix: summary")?;
+
+            // Open repository and read file from latest commit
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            let content = repository.file_at_commit(&commit, "a/b.txt")?;
+            assert_eq!(content, b"content");
+            assert!(repository.file_at_commit(&commit, "a").is_err());
+            assert!(repository.file_at_commit(&commit, "c.txt").is_err());
+            Ok(())
+        }
+    }
+
+    mod files_at_commit {
+        use std::path::PathBuf;
+
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_nested_files() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create a commit with files in nested directories
+            fixture.write("a/b/c.txt", "content")?;
+            fixture.write("d.txt", "content")?;
+            fixture.commit("fix: summary")?;
+
+            // Open repository and list files of latest commit
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert_eq!(
+                repository.files_at_commit(&commit)?,
+                [PathBuf::from("a/b/c.txt"), PathBuf::from("d.txt")]
+            );
+            Ok(())
+        }
+    }
+}
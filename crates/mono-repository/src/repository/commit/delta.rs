@@ -25,14 +25,19 @@
 
 //! Delta.
 
-use std::path::PathBuf;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 
 // ----------------------------------------------------------------------------
 // Enums
 // ----------------------------------------------------------------------------
 
 /// Delta.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Deltas are serialized with a `type` field denoting the variant, so they
+/// can be exported as part of JSON artifacts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
 pub enum Delta {
     /// Path was created.
     Create { path: PathBuf },
@@ -45,14 +50,17 @@ pub enum Delta {
 }
 
 // ----------------------------------------------------------------------------
-// Trait implementations
+// Implementations
 // ----------------------------------------------------------------------------
 
 #[allow(clippy::must_use_candidate)]
 impl Delta {
     /// Returns a reference to the path of the delta.
+    ///
+    /// For renames, this is the new path, and for deletions, the path that
+    /// was deleted, so the path always denotes the file that was affected.
     #[inline]
-    pub fn path(&self) -> &PathBuf {
+    pub fn path(&self) -> &Path {
         match self {
             Delta::Create { path, .. } => path,
             Delta::Modify { path, .. } => path,
@@ -60,4 +68,13 @@ impl Delta {
             Delta::Delete { path, .. } => path,
         }
     }
+
+    /// Returns a reference to the original path of the delta, if renamed.
+    #[inline]
+    pub fn from_path(&self) -> Option<&Path> {
+        match self {
+            Delta::Rename { from, .. } => Some(from),
+            _ => None,
+        }
+    }
 }
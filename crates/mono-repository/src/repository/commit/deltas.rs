@@ -26,6 +26,7 @@
 //! Iterator over deltas in a commit.
 
 use crate::repository::commit::Commit;
+use crate::repository::stats::DiffStats;
 use crate::repository::Result;
 
 use super::delta::Delta;
@@ -76,6 +77,24 @@ impl Commit<'_> {
     }
 }
 
+impl Deltas<'_> {
+    /// Returns the diff statistics of the deltas.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn stats(&self) -> Result<DiffStats> {
+        let stats = self.inner.stats()?;
+        Ok(DiffStats {
+            files_changed: stats.files_changed(),
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+        })
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Trait implementations
 // ----------------------------------------------------------------------------
@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Commit snapshot.
+
+use serde::{Deserialize, Serialize};
+
+use super::Commit;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Commit snapshot.
+///
+/// Commits borrow the repository, so they can't outlive it, which makes them
+/// unsuitable for caches or for passing them between threads. Snapshots copy
+/// all relevant information of a commit, and can be persisted with serde.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitSnapshot {
+    /// Commit identifier.
+    pub id: String,
+    /// Commit summary.
+    pub summary: String,
+    /// Commit body.
+    pub body: Option<String>,
+    /// Commit author.
+    pub author: String,
+    /// Commit timestamp.
+    pub timestamp: i64,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Commit<'_> {
+    /// Creates a snapshot of the commit, copying all relevant information.
+    #[must_use]
+    pub fn snapshot(&self) -> CommitSnapshot {
+        CommitSnapshot {
+            id: self.id().to_string(),
+            summary: self.summary().to_string(),
+            body: self.body().map(ToString::to_string),
+            author: self.author(),
+            timestamp: self.timestamp(),
+        }
+    }
+}
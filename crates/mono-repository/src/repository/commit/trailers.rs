@@ -59,6 +59,31 @@ impl Commit<'_> {
     pub fn trailers(&self) -> Result<Trailers> {
         Trailers::from_str(self.inner.body().unwrap_or_default())
     }
+
+    /// Returns all `Signed-off-by` trailers of the commit, as required by the
+    /// Developer Certificate of Origin (DCO), e.g., `Name <email>`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the trailers of the commit can't
+    /// be parsed.
+    pub fn signed_off_by(&self) -> Result<Vec<String>> {
+        let trailers = self.trailers()?;
+        let iter = trailers.get_all("Signed-off-by").map(ToString::to_string);
+        Ok(iter.collect())
+    }
+
+    /// Returns all `Reviewed-by` trailers of the commit, e.g., `Name <email>`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the trailers of the commit can't
+    /// be parsed.
+    pub fn reviewed_by(&self) -> Result<Vec<String>> {
+        let trailers = self.trailers()?;
+        let iter = trailers.get_all("Reviewed-by").map(ToString::to_string);
+        Ok(iter.collect())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -75,6 +100,26 @@ impl Trailers {
         })
     }
 
+    /// Creates an iterator over all values identified by the key.
+    ///
+    /// Trailers like `Co-authored-by` or `Reviewed-by` can appear multiple
+    /// times, which is why this method returns all values in order.
+    pub fn get_all<K>(&self, key: K) -> impl Iterator<Item = &str>
+    where
+        K: AsRef<str>,
+    {
+        let iter = self.inner.iter();
+        iter.filter_map(move |(candidate, value)| {
+            (candidate == key.as_ref()).then_some(value)
+        })
+    }
+
+    /// Creates an iterator over all co-authors, e.g., `Name <email>`.
+    #[inline]
+    pub fn co_authors(&self) -> impl Iterator<Item = &str> {
+        self.get_all("Co-authored-by")
+    }
+
     /// Returns whether the commit trailer set contains the key.
     pub fn contains_key<K>(&self, key: K) -> bool
     where
@@ -149,3 +194,95 @@ impl fmt::Debug for Trailers {
         f.debug_map().entries(self).finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod get_all {
+        use std::str::FromStr;
+
+        use crate::repository::commit::Trailers;
+        use crate::repository::Result;
+
+        #[test]
+        fn handles_repeated_keys() -> Result {
+            let trailers = Trailers::from_str(
+                "Co-authored-by: Alice <alice@example.com>\n\
+                 Reviewed-by: Bob <bob@example.com>\n\
+                 Co-authored-by: Carol <carol@example.com>\n",
+            )?;
+            assert_eq!(
+                trailers.co_authors().collect::<Vec<_>>(),
+                ["Alice <alice@example.com>", "Carol <carol@example.com>"]
+            );
+            assert_eq!(trailers.get_all("Signed-off-by").count(), 0);
+            Ok(())
+        }
+    }
+
+    mod reviewed_by {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_trailers() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.commit(
+                "fix: summary\n\nBody\n\n\
+                 Reviewed-by: Alice <alice@example.com>\n\
+                 Signed-off-by: Bob <bob@example.com>\n\
+                 Reviewed-by: Carol <carol@example.com>\n",
+            )?;
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert_eq!(
+                commit.reviewed_by()?,
+                ["Alice <alice@example.com>", "Carol <carol@example.com>"]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_missing_trailers() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.commit("fix: summary\n\nBody\n")?;
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert!(commit.reviewed_by()?.is_empty());
+            Ok(())
+        }
+    }
+
+    mod signed_off_by {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_trailers() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.commit(
+                "fix: summary\n\nBody\n\n\
+                 Signed-off-by: Alice <alice@example.com>\n\
+                 Reviewed-by: Bob <bob@example.com>\n",
+            )?;
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert_eq!(commit.signed_off_by()?, ["Alice <alice@example.com>"]);
+            Ok(())
+        }
+
+        #[test]
+        fn handles_missing_trailers() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.commit("fix: summary")?;
+            let repository = fixture.open()?;
+            let commit = repository.find("HEAD")?;
+            assert!(commit.signed_off_by()?.is_empty());
+            Ok(())
+        }
+    }
+}
@@ -26,6 +26,7 @@
 //! Iterator over commits in a repository.
 
 use std::ops::{Bound, RangeBounds};
+use std::path::{Path, PathBuf};
 
 use crate::repository::id::Id;
 use crate::repository::{Error, Repository, Result};
@@ -44,6 +45,8 @@ pub struct Commits<'a> {
     revwalk: git2::Revwalk<'a>,
     /// End of range.
     end: Option<Id>,
+    /// Path filter.
+    path: Option<PathBuf>,
 }
 
 // ----------------------------------------------------------------------------
@@ -116,7 +119,33 @@ impl Repository {
         };
 
         // Return iterator over commits
-        Ok(Commits { repository: self, revwalk, end })
+        Ok(Commits {
+            repository: self,
+            revwalk,
+            end,
+            path: None,
+        })
+    }
+
+    /// Creates an iterator over the commits touching the given path.
+    ///
+    /// This method works like [`Repository::commits`], but only yields commits
+    /// with at least one delta inside the given path, which is relative to the
+    /// root of the repository, e.g., to obtain the history of a package.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn commits_by_path<R, P>(
+        &self, range: R, path: P,
+    ) -> Result<Commits<'_>>
+    where
+        R: RangeBounds<Id>,
+        P: AsRef<Path>,
+    {
+        let mut commits = self.commits(range)?;
+        commits.path = Some(path.as_ref().to_path_buf());
+        Ok(commits)
     }
 }
 
@@ -129,16 +158,72 @@ impl<'a> Iterator for Commits<'a> {
 
     /// Returns the next commit.
     fn next(&mut self) -> Option<Self::Item> {
-        let id = match self.revwalk.next()? {
-            Ok(id) => id,
-            Err(err) => return Some(Err(err.into())),
-        };
+        loop {
+            let id = match self.revwalk.next()? {
+                Ok(id) => id,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            // Stop, if we've reached the end of the range
+            if self.end.as_deref() == Some(&id) {
+                return None;
+            }
+
+            // Return next commit, unless it doesn't touch the path filter
+            let Some(path) = &self.path else {
+                return Some(self.repository.get(id));
+            };
+            let res = self.repository.get(id).and_then(|commit| {
+                Ok(touches(&commit, path)?.then_some(commit))
+            });
+            match res {
+                Ok(Some(commit)) => return Some(Ok(commit)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns whether the given commit has a delta inside the given path.
+fn touches(commit: &Commit, path: &Path) -> Result<bool> {
+    let mut deltas = commit.deltas()?;
+    Ok(deltas.any(|delta| delta.path().starts_with(path)))
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod commits_by_path {
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_path_filter() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create a commit for each file, touching different directories
+            for path in ["a/file", "b/file", "a/other", "ab/file"] {
+                fixture.write(path, path)?;
+                fixture.commit(format!("fix: {path}"))?;
+            }
 
-        // Return next commit, if we haven't reached the end of the range
-        if self.end.as_deref() == Some(&id) {
-            None
-        } else {
-            Some(self.repository.get(id))
+            // Open repository and collect commits touching the path
+            let repository = fixture.open()?;
+            let summaries = repository
+                .commits_by_path(.., "a")?
+                .map(|res| res.map(|commit| commit.summary().to_string()))
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(summaries, ["fix: a/other", "fix: a/file"]);
+            Ok(())
         }
     }
 }
@@ -53,6 +53,15 @@ pub enum Error {
     /// Invalid version.
     #[error("invalid version")]
     Version,
+    /// Invalid version range.
+    #[error("invalid version range")]
+    Range,
+    /// Invalid remote URL.
+    #[error("invalid remote url")]
+    Remote,
+    /// Invalid tag format.
+    #[error("invalid tag format")]
+    Format,
 }
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,264 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Forge.
+
+use std::fmt;
+
+use super::error::{Error, Result};
+use super::id::Id;
+use super::Repository;
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Forge.
+///
+/// Forges are detected from the URL of a remote, which is necessary to create
+/// links to commits and issues, as each forge uses a slightly different URL
+/// scheme. Both, HTTPS and SSH remote URLs are supported. The host is matched
+/// against well-known names, so self-hosted instances are detected as well,
+/// as long as the host name contains the name of the forge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Forge {
+    /// GitHub.
+    GitHub {
+        /// Host name.
+        host: String,
+        /// Repository path, i.e., `owner/repo`.
+        path: String,
+    },
+    /// GitLab.
+    GitLab {
+        /// Host name.
+        host: String,
+        /// Repository path, i.e., `owner/repo`.
+        path: String,
+    },
+    /// Gitea, including Forgejo and Codeberg.
+    Gitea {
+        /// Host name.
+        host: String,
+        /// Repository path, i.e., `owner/repo`.
+        path: String,
+    },
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Repository {
+    /// Returns the URL of the remote with the given name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the remote doesn't exist, or
+    /// [`Error::Remote`] if the remote's URL is not valid UTF-8.
+    pub fn remote_url(&self, name: &str) -> Result<String> {
+        let remote = self.inner.find_remote(name)?;
+        remote.url().map(str::to_string).ok_or(Error::Remote)
+    }
+
+    /// Returns the URL of the `origin` remote.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the remote doesn't exist, or
+    /// [`Error::Remote`] if the remote's URL is not valid UTF-8.
+    #[inline]
+    pub fn origin_url(&self) -> Result<String> {
+        self.remote_url("origin")
+    }
+
+    /// Returns the forge of the `origin` remote, if it can be detected.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the remote doesn't exist, or
+    /// [`Error::Remote`] if the remote's URL is not valid UTF-8.
+    pub fn forge(&self) -> Result<Option<Forge>> {
+        self.origin_url().map(Forge::from_url)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Forge {
+    /// Attempts to detect the forge from the given remote URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mono_repository::Forge;
+    ///
+    /// // Detect forge from remote URL
+    /// let forge = Forge::from_url("git@github.com:zensical/mono.git");
+    /// assert!(matches!(forge, Some(Forge::GitHub { .. })));
+    /// ```
+    pub fn from_url<S>(url: S) -> Option<Self>
+    where
+        S: AsRef<str>,
+    {
+        let url = url.as_ref().trim();
+
+        // Strip scheme and user information, and split off the host, which is
+        // followed by a slash and might include a port, or, in SCP-like syntax
+        // as used for SSH, by a colon
+        let (has_scheme, rest) = match url.split_once("://") {
+            Some((_, rest)) => (true, rest),
+            None => (false, url),
+        };
+        let rest = rest.split_once('@').map_or(rest, |(_, rest)| rest);
+        let (host, path) = if has_scheme {
+            let (host, path) = rest.split_once('/')?;
+            (host.split_once(':').map_or(host, |(host, _)| host), path)
+        } else {
+            rest.split_once(':')?
+        };
+
+        // Strip the `.git` suffix from the path
+        let path = path.trim_matches('/');
+        let path = path.strip_suffix(".git").unwrap_or(path);
+        if path.is_empty() {
+            return None;
+        }
+
+        // Detect forge from host name
+        let (host, path) = (host.to_string(), path.to_string());
+        if host.contains("github") {
+            Some(Forge::GitHub { host, path })
+        } else if host.contains("gitlab") {
+            Some(Forge::GitLab { host, path })
+        } else if ["gitea", "forgejo", "codeberg"]
+            .iter()
+            .any(|name| host.contains(name))
+        {
+            Some(Forge::Gitea { host, path })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the URL of the commit with the given identifier.
+    #[must_use]
+    pub fn commit_url(&self, id: Id) -> String {
+        match self {
+            Forge::GitLab { .. } => format!("{self}/-/commit/{}", *id),
+            _ => format!("{self}/commit/{}", *id),
+        }
+    }
+
+    /// Returns the URL of the issue with the given number.
+    #[must_use]
+    pub fn issue_url(&self, number: u32) -> String {
+        match self {
+            Forge::GitLab { .. } => format!("{self}/-/issues/{number}"),
+            _ => format!("{self}/issues/{number}"),
+        }
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Forge {
+    /// Returns a reference to the host name.
+    #[inline]
+    pub fn host(&self) -> &str {
+        match self {
+            Forge::GitHub { host, .. }
+            | Forge::GitLab { host, .. }
+            | Forge::Gitea { host, .. } => host,
+        }
+    }
+
+    /// Returns a reference to the repository path.
+    #[inline]
+    pub fn path(&self) -> &str {
+        match self {
+            Forge::GitHub { path, .. }
+            | Forge::GitLab { path, .. }
+            | Forge::Gitea { path, .. } => path,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for Forge {
+    /// Formats the forge for display, which is the repository URL.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "https://{}/{}", self.host(), self.path())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod from_url {
+        use crate::repository::forge::Forge;
+
+        #[test]
+        fn handles_remote_urls() {
+            for url in [
+                "https://github.com/zensical/mono.git",
+                "https://github.com/zensical/mono",
+                "git@github.com:zensical/mono.git",
+                "ssh://git@github.com:22/zensical/mono.git",
+                "git@github.com:/zensical/mono.git",
+            ] {
+                let forge = Forge::from_url(url);
+                assert_eq!(
+                    forge,
+                    Some(Forge::GitHub {
+                        host: String::from("github.com"),
+                        path: String::from("zensical/mono"),
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn handles_forges() {
+            let forge = Forge::from_url("git@gitlab.com:group/sub/repo.git");
+            assert_eq!(
+                forge.map(|forge| forge.issue_url(1)),
+                Some(String::from(
+                    "https://gitlab.com/group/sub/repo/-/issues/1"
+                ))
+            );
+            let forge = Forge::from_url("https://codeberg.org/owner/repo");
+            assert!(matches!(forge, Some(Forge::Gitea { .. })));
+            let forge = Forge::from_url("https://example.com/owner/repo");
+            assert!(forge.is_none());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Diff statistics.
+
+use std::fmt;
+use std::ops::{AddAssign, RangeBounds};
+
+use super::id::Id;
+use super::{Repository, Result};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Diff statistics.
+///
+/// Statistics are accumulated over the commits of a range, which means that
+/// files changed in multiple commits are counted multiple times. This is fine
+/// for getting a quick sense of the size of a release, which is the intent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    /// Number of files changed.
+    pub files_changed: usize,
+    /// Number of lines inserted.
+    pub insertions: usize,
+    /// Number of lines deleted.
+    pub deletions: usize,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Repository {
+    /// Returns the diff statistics for the commits in the given range.
+    ///
+    /// This method accepts the same ranges as [`Repository::commits`]. Merge
+    /// commits are skipped, as their changes are already part of the commits
+    /// that were merged, and would otherwise be counted twice.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::Repository;
+    ///
+    /// // Open repository and obtain statistics of all commits
+    /// let repo = Repository::open(".")?;
+    /// let stats = repo.diff_stats(..)?;
+    /// println!("{stats}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff_stats<R>(&self, range: R) -> Result<DiffStats>
+    where
+        R: RangeBounds<Id>,
+    {
+        let mut stats = DiffStats::default();
+        for commit in self.commits(range)? {
+            let commit = commit?;
+            if !commit.is_merge() {
+                stats += commit.deltas()?.stats()?;
+            }
+        }
+
+        // Return accumulated statistics
+        Ok(stats)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl AddAssign for DiffStats {
+    /// Adds the given statistics.
+    fn add_assign(&mut self, other: Self) {
+        self.files_changed += other.files_changed;
+        self.insertions += other.insertions;
+        self.deletions += other.deletions;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl fmt::Display for DiffStats {
+    /// Formats the diff statistics for display, like `git diff --shortstat`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} file{} changed, {} insertion{}(+), {} deletion{}(-)",
+            self.files_changed,
+            plural(self.files_changed),
+            self.insertions,
+            plural(self.insertions),
+            self.deletions,
+            plural(self.deletions)
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod diff_stats {
+        use crate::repository::stats::DiffStats;
+        use crate::repository::Result;
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_commits() -> Result {
+            let fixture = Fixture::new()?;
+
+            // Create two commits, adding and then modifying a file
+            for content in ["a\nb\n", "a\nc\nd\n"] {
+                fixture.write("file.txt", content)?;
+                fixture.commit("chore: update file")?;
+            }
+
+            // Check accumulated statistics of both commits
+            let repository = fixture.open()?;
+            assert_eq!(
+                repository.diff_stats(..)?,
+                DiffStats {
+                    files_changed: 2,
+                    insertions: 4,
+                    deletions: 1
+                }
+            );
+            Ok(())
+        }
+    }
+}
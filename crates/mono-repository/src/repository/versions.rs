@@ -30,13 +30,18 @@ use std::collections::btree_map::{Iter, Range};
 use std::collections::BTreeMap;
 use std::fmt;
 use std::iter::Rev;
-use std::ops::RangeBounds;
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
 
 use super::commits::Commits;
 use super::error::{Error, Result};
 use super::id::Id;
 use super::Repository;
 
+mod format;
+
+pub use format::Format;
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
@@ -69,10 +74,26 @@ impl Repository {
     /// # Errors
     ///
     /// This method returns [`Error::Git`] if the operation fails.
+    #[inline]
     pub fn versions(&self) -> Result<Versions<'_>> {
-        let tags = self.inner.tag_names(Some("v[0-9]*.[0-9]*.[0-9]**"))?;
+        self.versions_with_format(&Format::default())
+    }
+
+    /// Returns the version set of the repository with the given tag format.
+    ///
+    /// This method works like [`Repository::versions`], but extracts versions
+    /// from tags abiding to the given format, e.g., `release-MAJOR.MINOR.PATCH`
+    /// or `pkg@MAJOR.MINOR.PATCH`, stripping prefix and suffix.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`] if the operation fails.
+    pub fn versions_with_format(
+        &self, format: &Format,
+    ) -> Result<Versions<'_>> {
+        let tags = self.inner.tag_names(Some(&format.glob()))?;
         let iter = tags.iter().flatten().map(|name| {
-            let version = name.trim_start_matches('v').parse()?;
+            let version = format.parse(name)?;
             Ok((version, self.find(name)?.id()))
         });
 
@@ -80,6 +101,26 @@ impl Repository {
         let tags = iter.collect::<Result<_>>()?;
         Ok(Versions { repository: self, tags })
     }
+
+    /// Returns the content of the file at the given path in the given version.
+    ///
+    /// This method resolves the commit of the given version, using the given
+    /// tag format, and reads the file from the commit's tree.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`] if the version doesn't exist, or
+    /// [`Error::Git`] if the operation fails.
+    pub fn file_at_version<P>(
+        &self, version: &Version, format: &Format, path: P,
+    ) -> Result<Vec<u8>>
+    where
+        P: AsRef<Path>,
+    {
+        let versions = self.versions_with_format(format)?;
+        let id = versions.get(version).ok_or(Error::Version)?;
+        self.file_at_commit(&self.get(*id)?, path)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -99,6 +140,42 @@ impl Versions<'_> {
         self.tags.contains_key(version)
     }
 
+    /// Returns the latest version and its commit identifier.
+    #[inline]
+    #[must_use]
+    pub fn latest(&self) -> Option<(&Version, &Id)> {
+        self.tags.last_key_value()
+    }
+
+    /// Returns the first version and its commit identifier.
+    #[inline]
+    #[must_use]
+    pub fn first(&self) -> Option<(&Version, &Id)> {
+        self.tags.first_key_value()
+    }
+
+    /// Returns the version preceding the given version.
+    #[inline]
+    #[must_use]
+    pub fn prev(&self, version: &Version) -> Option<(&Version, &Id)> {
+        self.tags.range(..version).next_back()
+    }
+
+    /// Returns the version following the given version.
+    #[inline]
+    #[must_use]
+    pub fn next_after(&self, version: &Version) -> Option<(&Version, &Id)> {
+        let range = (Bound::Excluded(version), Bound::Unbounded);
+        self.tags.range::<Version, _>(range).next()
+    }
+
+    /// Returns the n-th most recent version, where `0` denotes the latest.
+    #[inline]
+    #[must_use]
+    pub fn nth_latest(&self, n: usize) -> Option<(&Version, &Id)> {
+        self.tags.iter().nth_back(n)
+    }
+
     /// Creates a range iterator over the version set.
     #[inline]
     pub fn range<R>(&self, range: R) -> Range<'_, Version, Id>
@@ -109,49 +186,107 @@ impl Versions<'_> {
         self.tags.range(range)
     }
 
-    /// Creates an iterator over the commits for the given version.
+    /// Returns the commit range for the given version.
+    ///
+    /// The range starts at the commit tagged with the given version, and ends
+    /// just before the commit tagged with the previous version. If no version
+    /// is given, the range includes all commits since the latest version.
     ///
     /// # Errors
     ///
     /// This method returns [`Error::Version`] if the given version doesn't
-    /// exist, or [`Error::Git`] if the operation fails on the repository.
-    pub fn commits(&self, version: Option<&Version>) -> Result<Commits<'_>> {
+    /// exist in the version set.
+    pub fn bounds(
+        &self, version: Option<&Version>,
+    ) -> Result<(Bound<Id>, Bound<Id>)> {
         if let Some(version) = version {
-            if !self.tags.contains_key(version) {
+            let Some(start) = self.tags.get(version) else {
                 return Err(Error::Version);
-            }
+            };
 
             // In case the given version is the first version in the repository,
             // start at the commit tagged with this version and continue until
             // the first commit. Otherwise, stop just before the commit which
             // is tagged with the previous version.
-            let mut iter = self.tags.range(..=version).rev();
-            if let Some((_, start)) = iter.next() {
-                if let Some((_, end)) = iter.next() {
-                    self.repository.commits(start..end)
-                } else {
-                    self.repository.commits(start..)
-                }
-            } else {
-                Err(Error::Version)
-            }
+            let end = self.prev(version).map(|(_, end)| *end);
+            Ok((
+                Bound::Included(*start),
+                end.map_or(Bound::Unbounded, Bound::Excluded),
+            ))
         } else {
-            // No version given, so return all commits in the repository until
+            // No version given, so include all commits in the repository until
             // we either reach the previous version or the first commit
-            let mut iter = self.tags.range(..).rev();
-            if let Some((_, end)) = iter.next() {
-                self.repository.commits(..end)
-            } else {
-                self.repository.commits(..)
-            }
+            let end = self.latest().map(|(_, end)| *end);
+            Ok((
+                Bound::Unbounded,
+                end.map_or(Bound::Unbounded, Bound::Excluded),
+            ))
         }
     }
 
+    /// Creates an iterator over the commits for the given version.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`] if the given version doesn't
+    /// exist, or [`Error::Git`] if the operation fails on the repository.
+    #[inline]
+    pub fn commits(&self, version: Option<&Version>) -> Result<Commits<'_>> {
+        self.repository.commits(self.bounds(version)?)
+    }
+
+    /// Creates an iterator over the commits between the given versions.
+    ///
+    /// Iteration starts at the commit tagged with the `to` version, and stops
+    /// just before the commit tagged with the `from` version, which means that
+    /// all commits of all versions after `from` up to `to` are included.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`] if either version doesn't exist,
+    /// [`Error::Range`] if `from` isn't lower than `to`, or [`Error::Git`] if
+    /// the operation fails on the repository.
+    pub fn between(&self, from: &Version, to: &Version) -> Result<Commits<'_>> {
+        let (Some(end), Some(start)) = (self.tags.get(from), self.tags.get(to))
+        else {
+            return Err(Error::Version);
+        };
+
+        // Ensure versions are ordered, as the range would be empty otherwise
+        if from >= to {
+            return Err(Error::Range);
+        }
+
+        // Return iterator over commits
+        self.repository.commits(start..end)
+    }
+
     /// Creates an iterator over the version set.
+    ///
+    /// The iterator knows its exact length, so it can be used to pre-allocate
+    /// collections, and can be reversed to start at the first version.
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = (&Version, &Id)> {
+    #[must_use]
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (&Version, &Id)> + DoubleEndedIterator
+    {
         self.into_iter()
     }
+
+    /// Creates an iterator over the version set in chronological order.
+    ///
+    /// In contrast to [`Versions::iter`], which starts at the latest version,
+    /// this iterator starts at the first version, e.g., for accumulating the
+    /// changelogs of all versions in the order they were released.
+    #[inline]
+    #[must_use]
+    pub fn iter_chronological(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (&Version, &Id)> + DoubleEndedIterator
+    {
+        self.tags.iter()
+    }
 }
 
 #[allow(clippy::must_use_candidate)]
@@ -162,6 +297,14 @@ impl Versions<'_> {
         self.tags.len()
     }
 
+    /// Returns the number of versions.
+    ///
+    /// This method is an alias for [`Versions::len`].
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.len()
+    }
+
     /// Returns whether there are any versions.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -193,3 +336,172 @@ impl fmt::Debug for Versions<'_> {
             .finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::Result;
+    use crate::testing::Fixture;
+
+    /// Creates a repository with a tagged commit for each version.
+    fn fixture(versions: &[&str]) -> Result<Fixture> {
+        let fixture = Fixture::new()?;
+        for version in versions {
+            let id = fixture.commit(format!("chore: release v{version}"))?;
+            fixture.tag(format!("v{version}"), id)?;
+        }
+        Ok(fixture)
+    }
+
+    mod accessors {
+        use semver::Version;
+
+        use crate::repository::Result;
+
+        use super::fixture;
+
+        #[test]
+        fn handles_latest_first_and_prev() -> Result {
+            let fixture = fixture(&["0.1.0", "0.2.0", "1.0.0"])?;
+            let repository = fixture.open()?;
+            let versions = repository.versions()?;
+
+            // Check first, latest and previous versions
+            let version = |value: &str| value.parse::<Version>();
+            assert_eq!(
+                versions.first().map(|(v, _)| v),
+                Some(&version("0.1.0")?)
+            );
+            assert_eq!(
+                versions.latest().map(|(v, _)| v),
+                Some(&version("1.0.0")?)
+            );
+            assert_eq!(
+                versions.prev(&version("1.0.0")?).map(|(v, _)| v),
+                Some(&version("0.2.0")?)
+            );
+            assert_eq!(versions.prev(&version("0.1.0")?), None);
+            assert_eq!(
+                versions.next_after(&version("0.2.0")?).map(|(v, _)| v),
+                Some(&version("1.0.0")?)
+            );
+            assert_eq!(versions.next_after(&version("1.0.0")?), None);
+
+            // Check n-th latest versions and chronological order
+            assert_eq!(
+                versions.nth_latest(1).map(|(v, _)| v),
+                Some(&version("0.2.0")?)
+            );
+            assert_eq!(versions.nth_latest(3), None);
+            assert_eq!(
+                versions.iter_chronological().next().map(|(v, _)| v),
+                Some(&version("0.1.0")?)
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn handles_empty() -> Result {
+            let fixture = fixture(&[])?;
+            let repository = fixture.open()?;
+            let versions = repository.versions()?;
+            assert_eq!(versions.first(), None);
+            assert_eq!(versions.latest(), None);
+            Ok(())
+        }
+    }
+
+    mod between {
+        use semver::Version;
+
+        use crate::repository::{Error, Result};
+
+        use super::fixture;
+
+        #[test]
+        fn handles_range() -> Result {
+            let fixture = fixture(&["0.1.0", "0.2.0", "1.0.0"])?;
+            let repository = fixture.open()?;
+            let versions = repository.versions()?;
+
+            // Collect summaries of commits between first and latest version
+            let version = |value: &str| value.parse::<Version>();
+            let summaries = versions
+                .between(&version("0.1.0")?, &version("1.0.0")?)?
+                .map(|res| res.map(|commit| commit.summary().to_string()))
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(
+                summaries,
+                ["chore: release v1.0.0", "chore: release v0.2.0"]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_range() -> Result {
+            let fixture = fixture(&["0.1.0", "0.2.0"])?;
+            let repository = fixture.open()?;
+            let versions = repository.versions()?;
+
+            // Check reversed and unknown versions
+            let version = |value: &str| value.parse::<Version>();
+            assert!(matches!(
+                versions.between(&version("0.2.0")?, &version("0.1.0")?),
+                Err(Error::Range)
+            ));
+            assert!(matches!(
+                versions.between(&version("0.1.0")?, &version("1.0.0")?),
+                Err(Error::Version)
+            ));
+            Ok(())
+        }
+    }
+
+    mod file_at_version {
+        use semver::Version;
+
+        use crate::repository::versions::Format;
+        use crate::repository::{Error, Result};
+        use crate::testing::Fixture;
+
+        #[test]
+        fn handles_tag_format() -> Result {
+            let fixture = Fixture::new()?;
+            let format: Format = "release-{version}".parse()?;
+            for (major, content) in [(1, "a"), (2, "b")] {
+                fixture.write("file.txt", content)?;
+                let id = fixture.commit(format!("chore: release {major}"))?;
+                fixture.tag(format.tag(&Version::new(major, 0, 0)), id)?;
+            }
+
+            // Read file at first version
+            let repository = fixture.open()?;
+            let version = Version::new(1, 0, 0);
+            let content =
+                repository.file_at_version(&version, &format, "file.txt")?;
+            assert_eq!(content, b"a");
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_other_tag_format() -> Result {
+            let fixture = Fixture::new()?;
+            fixture.write("file.txt", "a")?;
+            let id = fixture.commit("chore: release")?;
+            fixture.tag("release-1.0.0", id)?;
+
+            // Read file with default tag format
+            let repository = fixture.open()?;
+            let version = Version::new(1, 0, 0);
+            let format = Format::default();
+            assert!(matches!(
+                repository.file_at_version(&version, &format, "file.txt"),
+                Err(Error::Version)
+            ));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Tag format.
+
+use semver::Version;
+use std::str::FromStr;
+
+use crate::repository::error::{Error, Result};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Tag format.
+///
+/// Tag formats describe how versions are mapped to tag names and vice versa.
+/// They are given as a template containing a `{version}` placeholder, which is
+/// surrounded by a fixed prefix and suffix, e.g., `v{version}`, which is the
+/// default, `release-{version}` or `pkg@{version}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Format {
+    /// Tag prefix.
+    prefix: String,
+    /// Tag suffix.
+    suffix: String,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Format {
+    /// Returns the glob pattern matching all tags of the format.
+    ///
+    /// Glob metacharacters in prefix and suffix are escaped, so they're matched
+    /// literally, e.g., for a format like `[pkg]{version}`.
+    #[must_use]
+    pub fn glob(&self) -> String {
+        let prefix = escape(&self.prefix);
+        let suffix = escape(&self.suffix);
+        format!("{prefix}[0-9]*.[0-9]*.[0-9]*{suffix}")
+    }
+
+    /// Returns the tag name for the given version.
+    #[must_use]
+    pub fn tag(&self, version: &Version) -> String {
+        format!("{}{version}{}", self.prefix, self.suffix)
+    }
+
+    /// Attempts to extract the version from the given tag name.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Version`] if the tag name doesn't match
+    /// the format, or [`Error::Semver`] if the version can't be parsed.
+    pub fn parse<S>(&self, name: S) -> Result<Version>
+    where
+        S: AsRef<str>,
+    {
+        let version = name
+            .as_ref()
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|name| name.strip_suffix(self.suffix.as_str()))
+            .ok_or(Error::Version)?;
+
+        // Parse version
+        Ok(version.parse()?)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Format {
+    /// Returns a reference to the tag prefix.
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns a reference to the tag suffix.
+    #[inline]
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl FromStr for Format {
+    type Err = Error;
+
+    /// Attempts to create a tag format from a template.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Format`] if the template doesn't contain
+    /// exactly one `{version}` placeholder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use mono_repository::versions::Format;
+    ///
+    /// // Create tag format from template
+    /// let format: Format = "release-{version}".parse()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(value: &str) -> Result<Self> {
+        let (prefix, suffix) =
+            value.split_once("{version}").ok_or(Error::Format)?;
+        if suffix.contains("{version}") {
+            return Err(Error::Format);
+        }
+
+        // Return tag format
+        Ok(Self {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Default for Format {
+    /// Creates the default tag format, which is `v{version}`.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            prefix: String::from("v"),
+            suffix: String::new(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Escapes glob metacharacters in the given value with backslashes.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for char in value.chars() {
+        if matches!(char, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+// ----------------------------------------------------------------------------
+// Tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    mod glob {
+        use crate::repository::versions::Format;
+        use crate::repository::Result;
+
+        #[test]
+        fn handles_formats() -> Result {
+            let format: Format = "pkg@{version}".parse()?;
+            assert_eq!(format.glob(), "pkg@[0-9]*.[0-9]*.[0-9]*");
+            Ok(())
+        }
+
+        #[test]
+        fn handles_metacharacters() -> Result {
+            let format: Format = "[pkg]*?{version}\\".parse()?;
+            assert_eq!(
+                format.glob(),
+                "\\[pkg\\]\\*\\?[0-9]*.[0-9]*.[0-9]*\\\\"
+            );
+            Ok(())
+        }
+    }
+
+    mod parse {
+        use semver::Version;
+
+        use crate::repository::versions::Format;
+        use crate::repository::{Error, Result};
+
+        #[test]
+        fn handles_formats() -> Result {
+            for (template, name) in [
+                ("v{version}", "v1.2.3"),
+                ("release-{version}", "release-1.2.3"),
+                ("pkg@{version}", "pkg@1.2.3"),
+            ] {
+                let format: Format = template.parse()?;
+                let version = format.parse(name)?;
+                assert_eq!(version, Version::new(1, 2, 3));
+                assert_eq!(format.tag(&version), name);
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn errors_on_invalid_template() {
+            for template in ["v", "{version}-{version}"] {
+                let res = template.parse::<Format>();
+                assert!(matches!(res, Err(Error::Format)));
+            }
+        }
+    }
+}
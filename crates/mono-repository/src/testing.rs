@@ -0,0 +1,226 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Test fixtures.
+
+use std::cell::Cell;
+use std::fs;
+use std::ops::Deref;
+use std::path::Path;
+use tempfile::TempDir;
+
+use crate::repository::{Repository, Result};
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+/// Timestamp of the first commit, incremented by one second for each commit.
+pub const TIMESTAMP: i64 = 1_700_000_000;
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Repository fixture.
+///
+/// Repositories are created in a temporary directory, which is removed when
+/// the fixture is dropped. All commits are authored by the same signature,
+/// and dated one second apart, starting at [`TIMESTAMP`], so history is
+/// deterministic. The fixture dereferences to the [`git2::Repository`] for
+/// everything not covered by its methods, e.g., creating branches or notes.
+pub struct Fixture {
+    /// Temporary directory.
+    dir: TempDir,
+    /// Git repository.
+    inner: git2::Repository,
+    /// Number of signatures created.
+    count: Cell<i64>,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Fixture {
+    /// Creates a repository in a temporary directory.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if the directory can't be created,
+    /// and [`Error::Git`][] if the repository can't be initialized.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    /// [`Error::Io`]: crate::repository::Error::Io
+    pub fn new() -> Result<Self> {
+        let dir = TempDir::new()?;
+        let inner = git2::Repository::init(dir.path())?;
+        Ok(Self {
+            dir,
+            inner,
+            count: Cell::new(0),
+        })
+    }
+
+    /// Opens the repository of the fixture.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    #[inline]
+    pub fn open(&self) -> Result<Repository> {
+        Repository::open(self.dir.path())
+    }
+
+    /// Writes a file, creating all parent directories as necessary.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Io`][] if the file can't be written.
+    ///
+    /// [`Error::Io`]: crate::repository::Error::Io
+    pub fn write<P, C>(&self, path: P, content: C) -> Result
+    where
+        P: AsRef<Path>,
+        C: AsRef<[u8]>,
+    {
+        let path = self.dir.path().join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write file
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Commits all files in the working directory on top of `HEAD`, if any.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn commit<M>(&self, message: M) -> Result<git2::Oid>
+    where
+        M: AsRef<str>,
+    {
+        let head = self.inner.head().ok();
+        let parent = head.and_then(|head| head.target());
+        self.commit_with(Some("HEAD"), message, parent.as_slice())
+    }
+
+    /// Commits all files in the working directory with the given parents,
+    /// updating the given reference, e.g., `HEAD`, if any.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn commit_with<M>(
+        &self, reference: Option<&str>, message: M, parents: &[git2::Oid],
+    ) -> Result<git2::Oid>
+    where
+        M: AsRef<str>,
+    {
+        let mut index = self.inner.index()?;
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None)?;
+        index.write()?;
+
+        // Resolve tree and parents, and create commit
+        let tree = self.inner.find_tree(index.write_tree()?)?;
+        let parents = parents
+            .iter()
+            .map(|&id| self.inner.find_commit(id).map_err(Into::into))
+            .collect::<Result<Vec<_>>>()?;
+        let signature = self.signature()?;
+        let id = self.inner.commit(
+            reference,
+            &signature,
+            &signature,
+            message.as_ref(),
+            &tree,
+            &parents.iter().collect::<Vec<_>>(),
+        )?;
+
+        // Return commit identifier
+        Ok(id)
+    }
+
+    /// Creates a lightweight tag pointing to the given commit.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn tag<N>(&self, name: N, id: git2::Oid) -> Result
+    where
+        N: AsRef<str>,
+    {
+        let object = self.inner.find_object(id, None)?;
+        self.inner.tag_lightweight(name.as_ref(), &object, false)?;
+        Ok(())
+    }
+
+    /// Returns the signature for the next commit.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Git`][] if the operation fails.
+    ///
+    /// [`Error::Git`]: crate::repository::Error::Git
+    pub fn signature(&self) -> Result<git2::Signature<'static>> {
+        let count = self.count.replace(self.count.get() + 1);
+        let time = git2::Time::new(TIMESTAMP + count, 0);
+        Ok(git2::Signature::new("Jane", "jane@example.com", &time)?)
+    }
+}
+
+#[allow(clippy::must_use_candidate)]
+impl Fixture {
+    /// Returns a reference to the path of the working directory.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Deref for Fixture {
+    type Target = git2::Repository;
+
+    /// Dereferences to the git repository.
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
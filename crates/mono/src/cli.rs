@@ -31,7 +31,7 @@ use clap::Parser;
 use std::path::PathBuf;
 use std::{env, process};
 
-use mono_project::{Manifest, Workspace};
+use mono_project::{self as project, Manifest, Workspace};
 use mono_repository::Repository;
 
 use crate::Context;
@@ -39,10 +39,12 @@ use crate::Context;
 mod command;
 mod config;
 mod error;
+mod format;
 
 pub use command::{Command, Commands};
-pub use config::Config;
-pub use error::Result;
+pub use config::{Config, Strategy};
+pub use error::{Error, Result};
+pub use format::Format;
 
 // ----------------------------------------------------------------------------
 // Constants
@@ -70,6 +72,9 @@ pub struct Cli {
     /// Working directory.
     #[arg(short, long, value_parser = valid, default_value = ".")]
     pub directory: PathBuf,
+    /// Fail instead of warn on shallow clones.
+    #[arg(long, global = true)]
+    pub fail_on_shallow: bool,
     /// Commands.
     #[command(subcommand)]
     command: Commands,
@@ -85,11 +90,20 @@ impl Cli {
     ) where
         T: Manifest,
     {
-        match self
-            .command
-            .execute(Context { repository, workspace, config })
-        {
+        let fail_on_shallow = self.fail_on_shallow;
+        match self.command.execute(Context {
+            repository,
+            workspace,
+            config,
+            fail_on_shallow,
+        }) {
             Ok(()) => process::exit(0),
+            Err(Error::Project(project::Error::Cycle(names))) => {
+                let first = names.first().cloned().unwrap_or_default();
+                eprintln!("Error: circular dependency between packages");
+                eprintln!("  {} → {first}", names.join(" → "));
+                process::exit(1)
+            }
             Err(err) => {
                 eprintln!("Error: {err}");
                 process::exit(1)
@@ -26,10 +26,15 @@
 //! List the names of all packages in topological order.
 
 use clap::Args;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
 
+use mono_project::workspace::Dependents;
 use mono_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -37,8 +42,56 @@ use crate::Context;
 // ----------------------------------------------------------------------------
 
 /// List the names of all packages in topological order.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
-pub struct Arguments {}
+pub struct Arguments {
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// Print versions as `name@version`.
+    #[arg(long)]
+    with_version: bool,
+    /// Print paths relative to the workspace, separated by a tab.
+    #[arg(long)]
+    with_path: bool,
+    /// Omit private packages, which must not be published.
+    #[arg(long)]
+    public_only: bool,
+    /// Print dependency graph in Graphviz DOT format.
+    #[arg(long, conflicts_with = "format")]
+    graph_dot: bool,
+}
+
+/// Package.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Package version.
+    version: String,
+    /// Package path, relative to the workspace.
+    path: PathBuf,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Creates an iterator over the packages in topological order, omitting
+    /// private packages, if desired.
+    fn filter<'a, T>(
+        &self, dependents: &'a Dependents<'a, T>,
+    ) -> impl Iterator<Item = usize> + 'a
+    where
+        T: Manifest,
+    {
+        let public_only = self.public_only;
+        dependents
+            .iter()
+            .filter(move |&node| !public_only || !dependents[node].is_private())
+    }
+}
 
 // ----------------------------------------------------------------------------
 // Trait implementations
@@ -50,12 +103,52 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
+        if self.graph_dot {
+            println!("{}", context.workspace.dependency_graph_dot()?);
+            return Ok(());
+        }
+
+        // Obtain paths of packages relative to the workspace, and collect all
+        // packages in topological order for writing to standard out
         let dependents = context.workspace.dependents()?;
-        for node in &dependents {
-            let name = dependents[node].name().expect("invariant");
-            println!("{name}");
+        let mut paths = context
+            .workspace
+            .packages()
+            .map(|(path, name)| (name, path))
+            .collect::<BTreeMap<_, _>>();
+        let mut output = Vec::new();
+        for node in self.filter(&dependents) {
+            let project = dependents[node];
+            let name = project.name().expect("invariant");
+            let version = project.version().expect("invariant").to_string();
+            let path = paths.remove(name).unwrap_or_default();
+            output.push(Output { name, version, path });
         }
 
+        // Print package names, optionally with versions and paths, omitting
+        // packages that are excluded from releases in the configuration
+        if self.format == Format::Plain {
+            let excluded = &context.config.version.exclude;
+            for Output { name, version, path } in output {
+                if excluded.iter().any(|exclude| exclude == name) {
+                    continue;
+                }
+                let mut line = name.to_string();
+                if self.with_version {
+                    line = format!("{line}@{version}");
+                }
+                if self.with_path {
+                    line = format!("{line}\t{}", path.display());
+                }
+                println!("{line}");
+            }
+            return Ok(());
+        }
+
+        // Write packages to standard out
+        serde_json::to_writer(io::stdout(), &output)?;
+        println!();
+
         // No errors occurred
         Ok(())
     }
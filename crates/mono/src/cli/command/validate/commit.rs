@@ -25,16 +25,19 @@
 
 //! Validate a commit message.
 
-use clap::{ArgGroup, Args};
+use clap::{ArgGroup, Args, ValueEnum};
 use cliclack::{confirm, input, outro};
 use console::style;
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::{fs, process};
+use std::{fs, process, result};
 
 use mono_changeset::change::{Error, Kind};
 use mono_changeset::Change;
 use mono_project::Manifest;
+use mono_repository::commit::Trailers;
+use mono_repository::{Commit, Id};
 
 use crate::cli::{Command, Result};
 use crate::Context;
@@ -44,11 +47,12 @@ use crate::Context;
 // ----------------------------------------------------------------------------
 
 /// Validate a commit message.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 #[command(group(
     ArgGroup::new("input")
         .required(true)
-        .args(&["summary", "file", "id"])
+        .args(&["summary", "file", "id", "range"])
 ))]
 pub struct Arguments {
     /// Commit message summary.
@@ -59,9 +63,141 @@ pub struct Arguments {
     /// Commit identifier.
     #[arg(short, long)]
     id: Option<String>,
+    /// Commit range in <from>..<to> format.
+    #[arg(short, long, value_parser = parse_range)]
+    range: Option<(String, String)>,
+    /// Stop at the first invalid commit in the range.
+    #[arg(long, requires = "range")]
+    fail_fast: bool,
+    /// Fix common formatting errors in the commit message file.
+    #[arg(long, requires = "file")]
+    fix: bool,
+    /// Require the scope to match a workspace package.
+    #[arg(short, long)]
+    workspace: bool,
     /// Prompt to add missing information.
     #[arg(short, long)]
     prompt: bool,
+    /// Allow only the given change kinds, e.g., `fix` for hotfixes.
+    #[arg(long, value_delimiter = ',', value_parser = Kind::from_str)]
+    allow_kinds: Vec<Kind>,
+    /// Deny the given change kinds.
+    #[arg(long, value_delimiter = ',', value_parser = Kind::from_str)]
+    deny_kinds: Vec<Kind>,
+    /// Require a `Signed-off-by` trailer, as mandated by the DCO.
+    #[arg(long, conflicts_with = "summary")]
+    dco: bool,
+    /// Require a `Reviewed-by` trailer in addition to `Signed-off-by`.
+    #[arg(long, requires = "dco")]
+    require_review: bool,
+    /// Match change kinds case-insensitively, e.g., `Fix` or `FEATURE`.
+    #[arg(long)]
+    lenient: bool,
+    /// Source of the commit message, as passed to git hooks.
+    #[arg(long, value_enum, requires = "file", conflicts_with = "prompt")]
+    source: Option<Source>,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Source of the commit message, as passed to git hooks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Source {
+    /// Message given with `-m` or `-F`.
+    Message,
+    /// Message from template.
+    Template,
+    /// Message of merge commit.
+    Merge,
+    /// Message of squash commit.
+    Squash,
+    /// Message of existing commit, e.g., when amending.
+    Commit,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Returns the allowed change kinds, or nothing if all kinds are allowed.
+    ///
+    /// Allowed and denied kinds given as arguments replace the ones from the
+    /// configuration, so they can be narrowed or widened for a single run.
+    fn kinds(&self, allowed: &[Kind], denied: &[Kind]) -> Option<Vec<Kind>> {
+        let allowed = match (&self.allow_kinds[..], allowed) {
+            ([], []) => &Kind::VALUES[..],
+            ([], allowed) | (allowed, _) => allowed,
+        };
+        let denied = if self.deny_kinds.is_empty() {
+            denied
+        } else {
+            &self.deny_kinds
+        };
+
+        // Remove denied kinds from allowed kinds
+        let kinds = allowed
+            .iter()
+            .filter(|kind| !denied.contains(kind))
+            .copied()
+            .collect::<Vec<_>>();
+
+        // Return kinds, unless all kinds are allowed
+        (!Kind::VALUES.iter().all(|kind| kinds.contains(kind))).then_some(kinds)
+    }
+
+    /// Validates the summary of the given commit, and ensures it complies with
+    /// the Developer Certificate of Origin (DCO), if desired.
+    fn validate(
+        &self, commit: &Commit, scopes: Option<&[String]>,
+        kinds: Option<&[Kind]>,
+    ) -> Result<bool> {
+        let (summary, id) = (commit.summary(), Some(commit.id()));
+        if parse_summary(summary, id, scopes, kinds, self.lenient).is_none() {
+            return Ok(false);
+        }
+
+        // Ensure commit is signed off and reviewed, if desired
+        if self.dco {
+            let signed = !commit.signed_off_by()?.is_empty();
+            let reviewed =
+                !self.require_review || !commit.reviewed_by()?.is_empty();
+            return Ok(check_dco(summary, id, signed, reviewed));
+        }
+
+        // Commit is valid
+        Ok(true)
+    }
+
+    /// Prints a hint on how to bypass the hook, if run from a Git hook.
+    ///
+    /// The hooks directory is read from `core.hooksPath`, as it's commonly
+    /// changed to share hooks, so the user knows which hook rejected it.
+    fn print_hook<T>(&self, context: &Context<T>) -> Result
+    where
+        T: Manifest,
+    {
+        if self.source.is_none() {
+            return Ok(());
+        }
+
+        // Print hooks directory, which defaults to `.git/hooks`
+        let path = context.repository.config_get("core.hooksPath")?;
+        let path = path.as_deref().unwrap_or(".git/hooks");
+        eprintln!(
+            "{}",
+            style(format!(
+                "Rejected by commit-msg hook in {path} – use \
+                 `git commit --no-verify` to bypass"
+            ))
+            .dim()
+        );
+
+        // No errors occurred
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -74,64 +210,120 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
+        // Skip validation of merge and squash commits, as they're generated
+        if matches!(self.source, Some(Source::Merge | Source::Squash)) {
+            return Ok(());
+        }
+
+        // Collect the names of all workspace packages, if scopes must match
+        let scopes = self.workspace.then(|| {
+            context
+                .workspace
+                .packages()
+                .map(|(_, name)| name.clone())
+                .collect::<Vec<_>>()
+        });
+        let scopes = scopes.as_deref();
+
+        // Determine the allowed change kinds, where arguments take precedence
+        // over configuration, and denied kinds are removed from allowed kinds
+        let config = &context.config.validate;
+        let kinds = self.kinds(&config.allowed_kinds, &config.denied_kinds);
+        let kinds = kinds.as_deref();
+
         // Validate a commit identifier
         if let Some(id) = &self.id {
             let commit = context.repository.find(id)?;
-            if parse_summary(commit.summary()).is_none() {
+            if commit.is_merge() {
+                print_merge(commit.id());
+            } else if !self.validate(&commit, scopes, kinds)? {
+                process::exit(1);
+            }
+
+        // Validate a commit range, reporting all invalid commits, unless we
+        // should stop at the first one
+        } else if let Some((from, to)) = &self.range {
+            context.ensure_complete_history()?;
+            let to = context.repository.find(to)?.id();
+            let from = context.repository.find(from)?.id();
+
+            // Start from the merge base, so we don't traverse past the point
+            // where the branches diverged, if the start has since advanced
+            let from = context.repository.merge_base(from, to)?.id();
+
+            // Traverse commits from newest to oldest, excluding the start
+            let mut valid = true;
+            for commit in context.repository.commits(to..from)? {
+                let commit = commit?;
+                if commit.is_merge() {
+                    print_merge(commit.id());
+                } else if !self.validate(&commit, scopes, kinds)? {
+                    valid = false;
+                    if self.fail_fast {
+                        break;
+                    }
+                }
+            }
+
+            // Exit with error if any commit is invalid
+            if !valid {
                 process::exit(1);
             }
 
         // Validate a commit message summary
         } else if let Some(summary) = &self.summary {
-            if parse_summary(summary).is_none() {
+            if parse_summary(summary, None, scopes, kinds, self.lenient)
+                .is_none()
+            {
                 process::exit(1);
             }
 
         // Validate a commit message file
         } else {
             let path = self.file.as_ref().expect("invariant");
-            let message = fs::read_to_string(path)?;
+            let mut message = fs::read_to_string(path)?;
+
+            // Fix common formatting errors in the summary, if desired, and
+            // write the message back to the file if it's valid afterwards
+            if self.fix {
+                let summary = message.lines().next().unwrap_or_default();
+                let fixed = fix_summary(summary);
+                if fixed != summary && Change::from_str(&fixed).is_ok() {
+                    eprintln!("{}", style(format!("- {summary}")).red());
+                    eprintln!("{}", style(format!("+ {fixed}")).green());
+                    message = match message.split_once('\n') {
+                        Some((_, rest)) => format!("{fixed}\n{rest}"),
+                        None => fixed,
+                    };
+                    fs::write(path, &message)?;
+                }
+            }
 
             // Retrieve first line, and parse as summary
             let summary = message.lines().next().unwrap_or_default();
-            let Some(change) = parse_summary(summary) else {
+            let Some(change) =
+                parse_summary(summary, None, scopes, kinds, self.lenient)
+            else {
+                self.print_hook(&context)?;
                 process::exit(1)
             };
 
-            // Prompt the user for missing information
-            let references = change.references();
-            if self.prompt && references.is_empty() {
-                if confirm("Is this commit related to an issue?")
-                    .initial_value(true)
-                    .interact()?
-                {
-                    // Prompt the number of the commit
-                    let num: u32 = input("What's the number of the issue?")
-                        .placeholder("  e.g. 123")
-                        .interact()?;
-
-                    // Append the reference to the first line
-                    let mut lines: Vec<&str> = message.lines().collect();
-                    let first = lines.get_mut(0).expect("invariant");
-                    let slice = format!("{first} (#{num})");
-                    *first = &slice;
-
-                    // Join lines and write back to file
-                    let new_message = lines.join("\n");
-                    fs::write(path, new_message)?;
-
-                    // Denote completion of prompt to the user
-                    outro(format!(
-                        "{} {}",
-                        style(format!("(#{num})")),
-                        style("added to commit summary").dim()
-                    ))?;
-
-                // Issue is not related to a commit
-                } else {
-                    outro(style("Nothing added to commit summary").dim())?;
+            // Ensure commit message is signed off and reviewed, if desired
+            if self.dco {
+                let trailers = Trailers::from_str(&message)?;
+                let signed = trailers.contains_key("Signed-off-by");
+                let reviewed = !self.require_review
+                    || trailers.contains_key("Reviewed-by");
+                if !check_dco(summary, None, signed, reviewed) {
+                    self.print_hook(&context)?;
+                    process::exit(1);
                 }
             }
+
+            // Prompt the user for missing information
+            if self.prompt && change.references().is_empty() {
+                prompt_reference(path, &message)?;
+            }
         }
 
         // No errors occurred
@@ -143,22 +335,203 @@ where
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Parses a commit range in `<from>..<to>` format, where `<to>` defaults to
+/// `HEAD`, like it does in Git.
+fn parse_range(value: &str) -> result::Result<(String, String), String> {
+    match value.split_once("..") {
+        Some((from, to)) if !from.is_empty() => {
+            let to = if to.is_empty() { "HEAD" } else { to };
+            Ok((from.to_string(), to.to_string()))
+        }
+        _ => Err(String::from("range must be in the format <from>..<to>")),
+    }
+}
+
+/// Fixes common formatting errors in the given commit summary.
+///
+/// This normalizes whitespace around the separator, lowercases the first
+/// letter of the description, unless it's an acronym, and strips trailing
+/// punctuation. Summaries without a separator are returned unchanged.
+fn fix_summary(summary: &str) -> String {
+    let Some((kind, description)) = summary.split_once(':') else {
+        return summary.to_string();
+    };
+
+    // Strip trailing punctuation and whitespace from the description
+    let description = description
+        .trim()
+        .trim_end_matches(['.', '!', '?', ',', ';', ':'])
+        .trim_end();
+
+    // Lowercase the first letter, unless the first word is an acronym
+    let word = description.split_whitespace().next().unwrap_or_default();
+    let is_acronym = word.chars().filter(|char| char.is_alphabetic()).count()
+        > 1
+        && word
+            .chars()
+            .all(|char| !char.is_alphabetic() || char.is_uppercase());
+    let mut chars = description.chars();
+    let description = match chars.next() {
+        Some(char) if !is_acronym => char.to_lowercase().chain(chars).collect(),
+        _ => description.to_string(),
+    };
+
+    // Return fixed summary
+    format!("{}: {description}", kind.trim())
+}
+
+/// Prompts the user for a related issue, and appends the reference to the
+/// summary of the commit message file at the given path.
+fn prompt_reference(path: &Path, message: &str) -> Result {
+    if confirm("Is this commit related to an issue?")
+        .initial_value(true)
+        .interact()?
+    {
+        // Prompt the number of the commit
+        let num: u32 = input("What's the number of the issue?")
+            .placeholder("  e.g. 123")
+            .interact()?;
+
+        // Append the reference to the first line
+        let mut lines: Vec<&str> = message.lines().collect();
+        let first = lines.get_mut(0).expect("invariant");
+        let slice = format!("{first} (#{num})");
+        *first = &slice;
+
+        // Join lines and write back to file
+        let new_message = lines.join("\n");
+        fs::write(path, new_message)?;
+
+        // Denote completion of prompt to the user
+        outro(format!(
+            "{} {}",
+            style(format!("(#{num})")),
+            style("added to commit summary").dim()
+        ))?;
+
+    // Issue is not related to a commit
+    } else {
+        outro(style("Nothing added to commit summary").dim())?;
+    }
+
+    // No errors occurred
+    Ok(())
+}
+
+/// Prints a notice that the merge commit with the given identifier is skipped.
+fn print_merge(id: Id) {
+    eprintln!(
+        "{} {} {}",
+        style("–").dim(),
+        style(id.short()).dim(),
+        style("Skipped merge commit, as it's not part of the changeset").dim()
+    );
+}
+
+/// Prints the given invalid commit summary to standard error.
+fn print_summary(summary: &str, id: Option<Id>) {
+    if let Some(id) = id {
+        eprintln!(
+            "{} {} {}",
+            style("✘").red(),
+            style(id.short()).dim(),
+            summary
+        );
+    } else {
+        eprintln!("{} {}", style("✘").red(), summary);
+    }
+}
+
+/// Checks whether a commit complies with the Developer Certificate of Origin
+/// (DCO), printing the commit summary and a hint to standard error if not.
+fn check_dco(
+    summary: &str, id: Option<Id>, signed: bool, reviewed: bool,
+) -> bool {
+    let hint = if !signed {
+        "Missing Signed-off-by trailer, use `git commit --signoff`"
+    } else if !reviewed {
+        "Missing Reviewed-by trailer"
+    } else {
+        return true;
+    };
+
+    // Write to standard error and add hint
+    print_summary(summary, id);
+    eprintln!("  {}", style(hint).dim());
+    false
+}
+
+/// Normalizes the case of the change kind in the given commit summary.
+///
+/// Summaries whose change kind can't be parsed are returned unchanged, so
+/// the error can be reported for the original summary.
+fn normalize_kind(summary: &str) -> Cow<'_, str> {
+    let end = summary.find(['(', '!', ':']).unwrap_or(summary.len());
+    match Kind::from_str_lenient(&summary[..end]) {
+        Ok(kind) => Cow::Owned(format!("{kind}{}", &summary[end..])),
+        Err(_) => Cow::Borrowed(summary),
+    }
+}
+
 /// Parses and validates the format of the given commit summary.
-fn parse_summary(summary: &str) -> Option<Change> {
-    let err = match Change::from_str(summary) {
-        Ok(change) => return Some(change),
+///
+/// If the identifier of the commit is given, it's printed alongside the
+/// summary in case of errors, so the commit can be identified easily. If
+/// scopes are given, the scope of the change must be one of them, and if
+/// kinds are given, the kind of the change must be one of them. If lenient,
+/// the kind of the change is matched case-insensitively.
+fn parse_summary(
+    summary: &str, id: Option<Id>, scopes: Option<&[String]>,
+    kinds: Option<&[Kind]>, lenient: bool,
+) -> Option<Change> {
+    let normalized = if lenient {
+        normalize_kind(summary)
+    } else {
+        Cow::Borrowed(summary)
+    };
+
+    // Parse summary, and check kind and scope against the given ones
+    let err = match Change::from_str(&normalized) {
+        Ok(change) => match (scopes, change.scope()) {
+            _ if kinds.is_some_and(|kinds| !kinds.contains(&change.kind())) => {
+                let kinds = kinds.expect("invariant");
+                print_summary(summary, id);
+                eprintln!(
+                    "  {} {}",
+                    style("Type must be one of:").dim(),
+                    kinds
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                return None;
+            }
+            (Some(scopes), Some(scope))
+                if !scopes.iter().any(|name| name == scope) =>
+            {
+                print_summary(summary, id);
+                eprintln!(
+                    "  {} {}",
+                    style("Scope must be one of:").dim(),
+                    scopes.join(", ")
+                );
+                return None;
+            }
+            _ => return Some(change),
+        },
         Err(err) => err,
     };
 
     // Write to standard error and add a useful hint, describing the error type
     // and being as specific as possible, to help the user fix the issue
-    eprintln!("{} {}", style("✘").red(), summary);
+    print_summary(summary, id);
     match err {
         Error::Format => {
             eprintln!(
                 "  {} {}",
                 style("Summary must be in the format").dim(),
-                style("<type>: <description>")
+                style("<type>[(<scope>)]: <description>")
             );
         }
         Error::Kind => {
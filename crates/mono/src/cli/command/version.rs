@@ -36,6 +36,8 @@ mod changed;
 mod changelog;
 mod create;
 mod list;
+mod next;
+mod release;
 
 // ----------------------------------------------------------------------------
 // Enums
@@ -52,6 +54,8 @@ pub enum Commands {
     Changelog(changelog::Arguments),
     /// List the names of changed packages in topological order.
     Changed(changed::Arguments),
+    /// Print the next version without creating it.
+    Next(next::Arguments),
 }
 
 // ----------------------------------------------------------------------------
@@ -69,6 +73,7 @@ where
             Commands::Changelog(args) => args.execute(context),
             Commands::Create(args) => args.execute(context),
             Commands::List(args) => args.execute(context),
+            Commands::Next(args) => args.execute(context),
         }
     }
 }
@@ -27,13 +27,16 @@
 
 use clap::Args;
 use semver::Version;
-use std::cmp;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::{cmp, io};
 
 use mono_changeset::Changeset;
 use mono_project::version::{Increment, VersionExt};
 use mono_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -46,6 +49,28 @@ pub struct Arguments {
     /// Version in x.y.z format
     #[arg(value_parser = Version::from_str_with_prefix)]
     version: Option<Version>,
+    /// List packages changed since the given commit, e.g., in pull requests.
+    #[arg(long, value_name = "SHA", conflicts_with = "version")]
+    since: Option<String>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// List paths of all changed files, one per line, instead of packages.
+    #[arg(long, conflicts_with = "format")]
+    paths: bool,
+}
+
+/// Changed package.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Package version.
+    version: String,
+    /// Package path, relative to the workspace.
+    path: PathBuf,
+    /// Version increment.
+    increment: Option<Increment>,
 }
 
 // ----------------------------------------------------------------------------
@@ -58,12 +83,31 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
+        // Ensure history is complete, or warn the user otherwise
+        context.ensure_complete_history()?;
+
         // Resolve versions and create changeset, then determine all commits
-        // that are either part of the given version or yet unreleased
-        let versions = context.repository.versions()?;
+        // that are either part of the given version or yet unreleased, or
+        // were added after the given commit, if any
+        let format = context.config.version.to_format(&context.workspace)?;
+        let versions = context.repository.versions_with_format(&format)?;
         let mut changeset = Changeset::new(&context.workspace)?;
-        for res in versions.commits(self.version.as_ref())? {
-            changeset.add(res?)?;
+        if let Some(since) = &self.since {
+            let from = context.repository.find(since)?.id();
+            changeset.add_range(&context.repository, from, None)?;
+        } else {
+            for res in versions.commits(self.version.as_ref())? {
+                changeset.add(res?)?;
+            }
+        }
+
+        // List paths of all changed files, if desired, which is useful to
+        // trigger selective rebuilds, e.g., for cache invalidation in CI
+        if self.paths {
+            for path in changeset.affected_paths()? {
+                println!("{}", path.display());
+            }
+            return Ok(());
         }
 
         // Obtain version increments, which denote which packages have changed,
@@ -79,7 +123,7 @@ where
                 // Traverse all deltas in the commit, and mark corresponding
                 // scopes as changed with at least a patch increment
                 for delta in commit.deltas()? {
-                    if let Some(node) = scopes.get(delta.path()) {
+                    if let Some(node) = scopes.get_longest(delta.path()) {
                         increments[node] =
                             cmp::max(increments[node], Some(Increment::Patch));
                     }
@@ -87,18 +131,45 @@ where
             }
         }
 
+        // Obtain paths of packages relative to the workspace for output
+        let mut paths = context
+            .workspace
+            .packages()
+            .map(|(path, name)| (name, path))
+            .collect::<BTreeMap<_, _>>();
+
         // Traverse dependents in topological order, and write names of changed
         // packages to standard output if they have a version increment
         let dependents = context.workspace.dependents()?;
+        let mut output = Vec::new();
         for node in &dependents {
             // In case no versions have been created so far, all packages must
             // be considered changed to be included in the initial release
-            if increments[node].is_some() || versions.is_empty() {
-                let name = dependents[node].name().expect("invariant");
-                println!("{name}");
+            let initial = versions.is_empty() && self.since.is_none();
+            if increments[node].is_some() || initial {
+                let project = dependents[node];
+                let name = project.name().expect("invariant");
+                match self.format {
+                    Format::Plain => println!("{name}"),
+                    Format::Json => output.push(Output {
+                        name,
+                        version: project
+                            .version()
+                            .expect("invariant")
+                            .to_string(),
+                        path: paths.remove(name).unwrap_or_default(),
+                        increment: increments[node],
+                    }),
+                }
             }
         }
 
+        // Write changed packages to standard out
+        if self.format == Format::Json {
+            serde_json::to_writer(io::stdout(), &output)?;
+            println!();
+        }
+
         // No errors occurred
         Ok(())
     }
@@ -25,13 +25,17 @@
 
 //! Generate the changelog of a version in Markdown format.
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use semver::Version;
 use std::borrow::Cow;
+use std::ops::Bound;
+use std::path::PathBuf;
 
-use mono_changeset::Changeset;
+use mono_changeset::changelog::{file, File};
+use mono_changeset::{Changeset, Error};
 use mono_project::version::VersionExt;
 use mono_project::Manifest;
+use mono_repository as repository;
 
 use crate::cli::{Command, Result};
 use crate::Context;
@@ -41,6 +45,7 @@ use crate::Context;
 // ----------------------------------------------------------------------------
 
 /// Generate the changelog of a version in Markdown format.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 pub struct Arguments {
     /// Version in x.y.z format
@@ -49,6 +54,89 @@ pub struct Arguments {
     /// Include version summary.
     #[arg(short, long)]
     summary: bool,
+    /// Include only changes after the given revision, e.g., a branch.
+    #[arg(long, conflicts_with = "version")]
+    from: Option<String>,
+    /// Limit changelog to the given scope.
+    #[arg(long)]
+    scope: Option<String>,
+    /// Append a section listing all contributors.
+    #[arg(long)]
+    contributors: bool,
+    /// Append aggregate diff statistics.
+    #[arg(long)]
+    stats: bool,
+    /// Output format of the changelog.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t,
+        requires_if("github", "version"),
+        conflicts_with_all = ["json", "write"]
+    )]
+    format: Format,
+    /// Prepend YAML front matter for static site generators.
+    #[arg(
+        long,
+        requires = "version",
+        conflicts_with_all = ["summary", "format", "json", "write"]
+    )]
+    frontmatter: bool,
+    /// Write changeset as JSON instead of the changelog.
+    #[arg(long, conflicts_with_all = ["summary", "contributors", "write"])]
+    json: bool,
+    /// Prepend changelog to file instead of writing to standard out.
+    #[arg(short, long, requires = "version")]
+    write: bool,
+    /// Changelog file, defaults to `CHANGELOG.md` of workspace or scope.
+    #[arg(long, requires = "write")]
+    changelog_file: Option<PathBuf>,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Output format of the changelog.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Markdown, as used for changelog files.
+    #[default]
+    Markdown,
+    /// Markdown, as used for GitHub Releases.
+    Github,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Returns the path of the changelog file to write.
+    ///
+    /// Unless a path is given explicitly, the changelog file of the scope is
+    /// used if a scope is given, or the one of the workspace otherwise.
+    fn changelog_file<T>(&self, context: &Context<T>) -> Result<PathBuf>
+    where
+        T: Manifest,
+    {
+        let root = context.repository.path();
+        let path = match (&self.changelog_file, &self.scope) {
+            (Some(path), _) => path.clone(),
+            (None, Some(scope)) => {
+                let mut iter = context.workspace.packages();
+                let (path, _) = iter
+                    .find(|(_, name)| name == scope)
+                    .ok_or_else(|| Error::Scope(scope.clone()))?;
+                root.join(path).join("CHANGELOG.md")
+            }
+            (None, None) => root.join("CHANGELOG.md"),
+        };
+
+        // Return changelog file path
+        Ok(path)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -61,15 +149,49 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
+        // Ensure history is complete, or warn the user otherwise
+        context.ensure_complete_history()?;
+
         // Resolve versions and create changeset, then determine all commits
         // that are either part of the given version or yet unreleased
-        let versions = context.repository.versions()?;
-        let mut changeset = Changeset::with_config(
-            &context.workspace,
-            &context.config.changeset,
-        )?;
-        for res in versions.commits(self.version.as_ref())? {
-            changeset.add(res?)?;
+        let format = context.config.version.to_format(&context.workspace)?;
+        let versions = context.repository.versions_with_format(&format)?;
+        let config = &context.config.changeset;
+        let mut range = versions.bounds(self.version.as_ref())?;
+        let mut changeset = if let Some(from) = &self.from {
+            // Start from the merge base, so only changes are included that
+            // were added since the branch diverged from the given revision
+            let from = context.repository.find(from)?.id();
+            let from = context.repository.merge_base_with_head(from)?.id();
+            range = (Bound::Unbounded, Bound::Excluded(from));
+            Changeset::from_commit_range_with_config(
+                &context.workspace,
+                config,
+                &context.repository,
+                from,
+                None,
+            )?
+        } else {
+            let mut changeset =
+                Changeset::with_config(&context.workspace, config)?;
+            for res in versions.commits(self.version.as_ref())? {
+                changeset.add(res?)?;
+            }
+            changeset
+        };
+
+        // Link commits and issues, if the forge of the remote can be detected
+        changeset.set_forge(context.repository.forge().ok().flatten());
+
+        // Warn about unknown scopes, if strict scopes are set to warn
+        for scope in changeset.unknown_scopes() {
+            eprintln!("Warning: change references unknown scope `{scope}`");
+        }
+
+        // Write changeset as JSON to standard out, if desired
+        if self.json {
+            println!("{}", changeset.to_json()?);
+            return Ok(());
         }
 
         // Create queue for writing to standard out - since we are particularly
@@ -79,15 +201,83 @@ where
             queue.push(Cow::Borrowed(changeset.summary()?));
         }
 
-        // Generate changelog, and append to queue if it's not empty - we also
-        // need to support summary-only releases, i.e., pure version bumps
-        let changelog = changeset.to_changelog();
-        if !changelog.is_empty() {
+        // Obtain release date from commit the version's tag points to
+        let timestamp = match &self.version {
+            Some(version) => {
+                let id =
+                    versions.get(version).ok_or(repository::Error::Version)?;
+                Some(context.repository.get(*id)?.timestamp())
+            }
+            None => None,
+        };
+
+        // Generate changelog in the configured order of sections, and append
+        // to queue if it's not empty - we also need to support summary-only
+        // releases, i.e., pure version bumps
+        let order = &context.config.changelog.section_order;
+        let mut changelog = match &self.scope {
+            Some(scope) => changeset.to_changelog_for_scope(scope),
+            None => changeset.to_changelog(),
+        }
+        .with_order(order);
+
+        // Date changelog, unless it's written to a file, as the file's heading
+        // for each version already includes the release date
+        if let Some(timestamp) = timestamp.filter(|_| !self.write) {
+            changelog = changelog.with_date(timestamp);
+        }
+        if let Some(version) = self
+            .version
+            .as_ref()
+            .filter(|_| self.format == Format::Github)
+        {
+            let url = context.repository.forge().ok().flatten();
+            let url = url.map(|forge| forge.to_string()).unwrap_or_default();
+            queue.push(Cow::Owned(
+                changelog.to_github_releases_markdown(&url, version),
+            ));
+        } else if let Some(version) =
+            self.version.as_ref().filter(|_| self.frontmatter)
+        {
+            let date = timestamp.map(file::date);
+            queue.push(Cow::Owned(
+                changelog
+                    .to_markdown_with_frontmatter(version, date.as_deref()),
+            ));
+        } else if !changelog.is_empty() {
             queue.push(Cow::Owned(changelog.to_string()));
         }
 
+        // Append contributors, if desired and there are any
+        let contributors = self
+            .contributors
+            .then(|| changeset.contributors())
+            .transpose()?
+            .filter(|contributors| !contributors.is_empty());
+        if let Some(contributors) = contributors {
+            let iter = contributors.iter().map(|name| format!("- {name}"));
+            let list = iter.collect::<Vec<_>>().join("\n");
+            queue.push(Cow::Owned(format!("### Contributors\n\n{list}")));
+        }
+
+        // Append diff statistics, if desired
+        if self.stats {
+            let stats = context.repository.diff_stats(range)?;
+            queue.push(Cow::Owned(format!("### Statistics\n\n{stats}")));
+        }
+
+        // Prepend everything to the changelog file, dated by the version's tag
+        let release = self.version.as_ref().zip(timestamp);
+        if let Some((version, timestamp)) = release.filter(|_| self.write) {
+            let mut file = File::read(self.changelog_file(&context)?)?;
+
+            // Prepend entry, erroring if the version was already written
+            let entry = queue.join("\n\n");
+            file.prepend(format.tag(version), timestamp, entry)?;
+            file.write()?;
+
         // Write everything to standard out
-        if !queue.is_empty() {
+        } else if !queue.is_empty() {
             println!("{}", queue.join("\n\n"));
         }
 
@@ -25,30 +25,513 @@
 
 //! Create a new version and update all packages.
 
-use clap::Args;
-use cliclack::{confirm, outro, select};
-use console::style;
-use std::io::Write;
-use std::{fs, process};
+use clap::{ArgGroup, Args, ValueEnum};
+use cliclack::{confirm, note, outro, select};
+use console::{measure_text_width, pad_str, style, Alignment};
+use semver::Version;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::{env, fs, process};
 use tempfile::NamedTempFile;
 
-use mono_changeset::Changeset;
-use mono_project::version::VersionExt;
-use mono_project::Manifest;
+use mono_changeset::{Changelog, Changeset};
+use mono_project::version::{Increment, VersionExt};
+use mono_project::workspace::Dependents;
+use mono_project::{Manifest, Workspace};
+use mono_repository::{self as repository, Forge, Id, Repository};
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Config, Error, Result, Strategy};
 use crate::Context;
 
+use super::release::github::{self, Release};
+
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
 /// Create a new version and update all packages.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
+#[command(group(
+    ArgGroup::new("release_notes").args(&["message", "message_file"])
+))]
 pub struct Arguments {
+    /// Run without prompts, committing to the current branch and pushing it
+    /// with the tag, for automated releases.
+    ///
+    /// Implies `--no-interactive`, `--push`, `--tag` and `--no-branch`. Release
+    /// notes are taken from `MONO_RELEASE_MESSAGE`, if not given, or generated
+    /// from the changelog, and a summary is printed as JSON.
+    #[arg(long, conflicts_with = "dry_run")]
+    batch: bool,
     /// Use visual editor for release notes.
     #[arg(short, long)]
     visual: bool,
+    /// Print planned actions without applying them.
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+    /// Skip all prompts, selecting the highest version increments.
+    #[arg(short = 'y', long, requires = "release_notes")]
+    no_interactive: bool,
+    /// Release notes, skipping the editor.
+    #[arg(short, long)]
+    message: Option<String>,
+    /// Read release notes from file.
+    #[arg(long)]
+    message_file: Option<PathBuf>,
+    /// Push release branch after commit.
+    #[arg(long)]
+    push: bool,
+    /// Commit to the current branch instead of creating a release branch.
+    #[arg(long)]
+    no_branch: bool,
+    /// Create GitHub release for version after push, using `GITHUB_TOKEN`.
+    #[arg(long, requires = "push", requires = "tag")]
+    github_release: bool,
+    /// Create annotated tag for version after commit.
+    #[arg(long)]
+    tag: bool,
+    /// Sign commit and tag using GPG.
+    #[arg(short = 'S', long)]
+    sign: bool,
+    /// Omit `Signed-off-by` trailer from release commit.
+    #[arg(long)]
+    no_signoff: bool,
+    /// Skip check for uncommitted changes (advanced, use with care).
+    #[arg(long)]
+    allow_dirty: bool,
+    /// Skip check for default branch (advanced, use with care).
+    #[arg(long)]
+    allow_any_branch: bool,
+    /// Fetch tags from the configured remote before resolving versions.
+    #[arg(long)]
+    fetch: bool,
+    /// Skip synchronization of lock files after updating manifests.
+    #[arg(long)]
+    no_lock_sync: bool,
+    /// Create pre-release with identifier, e.g., `rc` for `2.0.0-rc.1`.
+    #[arg(long, value_name = "IDENTIFIER")]
+    pre_release: Option<String>,
+    /// Set the given version for all packages, if no versions exist yet.
+    #[arg(
+        long,
+        value_name = "VERSION",
+        value_parser = Version::from_str_with_prefix,
+        conflicts_with = "pre_release"
+    )]
+    initial: Option<Version>,
+    /// Increment all packages are bumped with for the initial version.
+    #[arg(long, value_enum, default_value_t, requires = "initial")]
+    initial_increment: InitialIncrement,
+    /// Exclude the given package from the release (repeatable).
+    #[arg(long, value_name = "NAME")]
+    exclude: Vec<String>,
+}
+
+/// Effective flags.
+///
+/// Batch mode implies several flags, so they're resolved once before creating
+/// the release, and passed to all steps that depend on them.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug)]
+struct Flags {
+    /// Whether to prompt the user.
+    interactive: bool,
+    /// Whether to push the branch and tag.
+    push: bool,
+    /// Whether to create an annotated tag.
+    tag: bool,
+    /// Whether to create a release branch.
+    branch: bool,
+}
+
+/// Release guard.
+///
+/// Creating a release modifies the repository in several steps, i.e., a branch
+/// is created, manifests are updated, and changes are committed. If any step
+/// fails, the guard rolls back to the original state when dropped, unless it
+/// was disarmed after all steps succeeded.
+struct ReleaseGuard<'a> {
+    /// Repository.
+    repository: &'a Repository,
+    /// Original branch, if not detached.
+    branch: Option<String>,
+    /// Original `HEAD`.
+    head: Id,
+    /// Release branch.
+    release: String,
+    /// Whether the release branch was created by the guard.
+    created: bool,
+    /// Whether the working directory was clean.
+    clean: bool,
+    /// Whether to roll back on drop.
+    armed: bool,
+}
+
+/// Release summary, printed in batch mode.
+#[derive(Debug, Serialize)]
+struct Summary<'a> {
+    /// Release branch.
+    branch: &'a str,
+    /// Release tag.
+    tag: &'a str,
+    /// Bumped packages.
+    packages: Vec<Package<'a>>,
+}
+
+/// Bumped package.
+#[derive(Debug, Serialize)]
+struct Package<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Previous version.
+    from: Option<&'a Version>,
+    /// New version.
+    to: &'a Version,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Increment for the initial version.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum InitialIncrement {
+    /// Major increment.
+    #[default]
+    Major,
+    /// Minor increment.
+    Minor,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> ReleaseGuard<'a> {
+    /// Records the state of the repository before creating the release branch.
+    fn new(repository: &'a Repository, release: &str) -> Result<Self> {
+        Ok(Self {
+            repository,
+            branch: repository.current_branch()?,
+            head: repository.find("HEAD")?.id(),
+            release: release.to_string(),
+            created: false,
+            clean: repository.is_clean()?,
+            armed: true,
+        })
+    }
+
+    /// Creates the release branch, which is deleted on rollback.
+    fn branch(&mut self) -> repository::Result {
+        self.repository.branch(&self.release)?;
+        self.created = true;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Disarms the guard after all steps succeeded.
+    fn disarm(mut self) {
+        self.armed = false;
+    }
+
+    /// Rolls back to the original branch, and deletes the release branch.
+    fn rollback(&self) -> repository::Result {
+        self.repository.reset_hard(self.head)?;
+        match &self.branch {
+            Some(branch) => self.repository.checkout(branch)?,
+            None => self.repository.checkout_detached(self.head)?,
+        }
+
+        // Delete release branch, but only if it was created by the guard, as
+        // we must never delete a branch that existed before
+        if self.created {
+            self.repository.branch_delete(&self.release)?;
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Arguments {
+    /// Ensures that all preconditions for creating a release are met.
+    ///
+    /// Returns `false` if the release should be aborted, after informing the
+    /// user, and an error if a precondition can't be met without user action.
+    fn check<T>(
+        &self, context: &Context<T>, forge: Option<&Forge>,
+    ) -> Result<bool>
+    where
+        T: Manifest,
+    {
+        // Ensure working directory is clean, unless explicitly allowed, which
+        // might include unrelated changes in the release commit
+        if self.allow_dirty {
+            print_warning("Skipping check for uncommitted changes");
+        } else if !context.repository.is_clean()? {
+            eprintln!("Working directory contains changes");
+            return Ok(false);
+        }
+
+        // Ensure we're on the default branch, unless explicitly allowed, which
+        // might lead to versions that diverge from the default branch. Since
+        // pre-releases are often created from feature branches, we skip it
+        if self.allow_any_branch {
+            print_warning("Skipping check for default branch");
+        } else if self.pre_release.is_none()
+            && !context
+                .repository
+                .on_named_branch(&context.config.default_branches)?
+        {
+            eprintln!("Not on default branch");
+            return Ok(false);
+        }
+
+        // Ensure signing is configured if explicitly requested, so we don't
+        // fail after the release was prepared. If Git is configured to always
+        // sign commits, Git itself reports the failure when committing
+        if self.sign && !context.repository.is_signing_configured()? {
+            return Err(Error::Signing);
+        }
+
+        // Ensure the GitHub token is set and the remote is hosted on GitHub,
+        // so we don't fail after the release was pushed
+        if self.github_release {
+            github::token()?;
+            if !matches!(forge, Some(Forge::GitHub { .. })) {
+                return Err(Error::GitHubRemote);
+            }
+        }
+
+        // All preconditions are met
+        Ok(true)
+    }
+
+    /// Returns the effective flags, as implied by batch mode.
+    fn flags(&self) -> Flags {
+        Flags {
+            interactive: !(self.no_interactive || self.batch),
+            push: self.push || self.batch,
+            tag: self.tag || self.batch,
+            branch: !(self.no_branch || self.batch),
+        }
+    }
+
+    /// Publishes a GitHub release for the given tag, version and release notes,
+    /// including the changelog, which links commits and issues.
+    fn publish(
+        &self, changelog: &Changelog, forge: &Forge, tag: &str,
+        version: &Version, summary: &str,
+    ) -> Result {
+        let changelog =
+            changelog.to_github_releases_markdown(&forge.to_string(), version);
+
+        // Create release with release notes and changelog
+        let body = format!("{summary}\n\n{changelog}");
+        let prerelease = self.pre_release.is_some();
+        Release::new(tag, &body, prerelease).publish(forge)?;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Returns the release notes, prompting the user if none were given.
+    ///
+    /// In batch mode, release notes are read from `MONO_RELEASE_MESSAGE`, or
+    /// generated from the given changelog, if not given.
+    fn release_notes(&self, changelog: &Changelog) -> Result<String> {
+        if let Some(message) = &self.message {
+            Ok(message.clone())
+        } else if let Some(path) = &self.message_file {
+            Ok(fs::read_to_string(path)?)
+        } else if self.batch {
+            let message = env::var("MONO_RELEASE_MESSAGE").ok();
+            Ok(message.unwrap_or_else(|| changelog.to_string()))
+        } else {
+            prompt_commit_message(self.visual)
+        }
+    }
+
+    /// Selects version increments for all packages.
+    ///
+    /// Dependents are traversed in topological order, to let the user review
+    /// version increment suggestions in lock-step for choosing. If packages
+    /// are bumped in lockstep, or if there's only a single package, a single
+    /// increment is selected for all packages, which are then set to the same
+    /// version, see [`target`]. For the first release, all packages
+    /// are bumped with the initial increment, as they're set to the initial
+    /// version. If prompts are disabled, the highest increments are selected.
+    fn select_all<T>(
+        &self, context: &Context<T>, dependents: &Dependents<T>,
+        increments: &mut [Option<Increment>], max: Option<Increment>,
+        first: bool, flags: Flags,
+    ) -> Result
+    where
+        T: Manifest,
+    {
+        let excluded = excluded(&self.exclude, &context.config);
+        if first {
+            let count = context.workspace.packages_count();
+            let increment = self.initial_increment.into();
+            increments[..count].fill(Some(increment));
+            exclude(dependents, increments, &excluded);
+            return Ok(());
+        }
+
+        // Select a single increment, if packages are bumped in lockstep, and
+        // determine the suggested version increments for all dependents
+        let lockstep = is_lockstep(context);
+        let max = if lockstep {
+            select_uniform(max, flags)?
+        } else {
+            max
+        };
+        let preview = suggest(context, dependents, increments, max, &excluded)?;
+
+        // Show suggested versions, and let the user adjust them, if desired,
+        // unless no package is bumped, so there's nothing to adjust
+        let empty = preview.iter().all(Option::is_none);
+        if !flags.interactive || lockstep || empty {
+            increments.copy_from_slice(&preview);
+        } else {
+            self.print_preview(dependents, &preview)?;
+            if confirm("Adjust any of these?")
+                .initial_value(false)
+                .interact()?
+            {
+                select_increments(dependents, increments, true, &excluded)?;
+            } else {
+                increments.copy_from_slice(&preview);
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Prints the suggested versions of all packages that will be bumped.
+    ///
+    /// Packages are listed in topological order, together with their current
+    /// and next version, as well as the increment, aligned in columns.
+    fn print_preview<T>(
+        &self, dependents: &Dependents<T>, increments: &[Option<Increment>],
+    ) -> Result
+    where
+        T: Manifest,
+    {
+        let mut rows = Vec::new();
+        for node in dependents {
+            if let Some(increment) = increments[node] {
+                let project = dependents[node];
+                let name = project.name().expect("invariant");
+                let current = project.version().expect("invariant");
+                let pre_release = self.pre_release.as_deref();
+                let next = next_version(current, increment, pre_release)?;
+                rows.push((name, current.to_string(), next, increment));
+            }
+        }
+
+        // Determine column widths, and align names and versions
+        let iter = rows.iter().map(|row| measure_text_width(row.0));
+        let name_width = iter.max().unwrap_or_default();
+        let iter = rows.iter().map(|row| measure_text_width(&row.1));
+        let version_width = iter.max().unwrap_or_default();
+        let lines = rows.iter().map(|(name, current, next, increment)| {
+            format!(
+                "{}  {}  →  {next}  {}",
+                pad_str(name, name_width, Alignment::Left, None),
+                pad_str(current, version_width, Alignment::Left, None),
+                style(format!("({increment})")).dim()
+            )
+        });
+
+        // Print suggested versions
+        let lines = lines.collect::<Vec<_>>();
+        note("Suggested versions", lines.join("\n"))?;
+
+        // No errors occurred
+        Ok(())
+    }
+
+    /// Applies the given version increments to all packages in the workspace,
+    /// or sets the target version, if given, and returns the new versions.
+    fn bump<T>(
+        &self, workspace: Workspace<T>, increments: &[Option<Increment>],
+        target: Option<&Version>,
+    ) -> Result<BTreeMap<String, Version>>
+    where
+        T: Manifest,
+    {
+        let sync = !self.no_lock_sync;
+        let bumped = if let Some(version) = target {
+            workspace.bump_to(increments, version, sync)?
+        } else if let Some(identifier) = &self.pre_release {
+            workspace.bump_pre(increments, identifier, sync)?
+        } else {
+            workspace.bump(increments, sync)?
+        };
+
+        // Remind the user to update lock files, if synchronization was skipped
+        if !sync {
+            print_warning(
+                "Skipped synchronization, update lock files manually",
+            );
+        }
+
+        // Return new versions
+        Ok(bumped)
+    }
+
+    /// Prints the planned actions for a dry run.
+    ///
+    /// If a target version is given, all packages are bumped to it.
+    fn print_plan<T>(
+        &self, dependents: &Dependents<T>, increments: &[Option<Increment>],
+        target: Option<&Version>, tag: &str,
+    ) -> Result
+    where
+        T: Manifest,
+    {
+        if self.no_branch {
+            println!("Would commit to current branch");
+        } else {
+            println!("Would create branch release/{tag}");
+        }
+        let pre_release = self.pre_release.as_deref();
+        for node in dependents {
+            if increments[node].is_some() {
+                let project = dependents[node];
+                let name = project.name().expect("invariant");
+                let current = project.version().expect("invariant");
+                let next = package_version(
+                    dependents,
+                    node,
+                    increments,
+                    target,
+                    pre_release,
+                )?;
+                println!("Would bump {name} from {current} to {next}");
+            }
+        }
+        println!("Would commit with summary: chore: release {tag}");
+        if self.tag {
+            println!("Would create tag {tag}");
+        }
+        if self.push && self.no_branch {
+            println!("Would push current branch");
+        } else if self.push {
+            println!("Would push branch release/{tag}");
+        }
+
+        // No errors occurred
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -61,9 +544,20 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
+        let flags = self.flags();
+
+        // Fetch tags from remote, if desired, as local tags might be stale
+        if self.fetch {
+            context.repository.fetch(&context.config.remote)?;
+        }
+
+        // Ensure history is complete, or warn the user otherwise
+        context.ensure_complete_history()?;
+
         // Resolve versions and create changeset, then determine all commits
         // that were added after the latest version was released
-        let versions = context.repository.versions()?;
+        let format = context.config.version.to_format(&context.workspace)?;
+        let versions = context.repository.versions_with_format(&format)?;
         let mut changeset = Changeset::new(&context.workspace)?;
         for res in versions.commits(None)? {
             changeset.add(res?)?;
@@ -72,60 +566,49 @@ where
         // Obtain version increments, which denote which packages have changed,
         // and abort immediately if there are no changes that require a release
         let mut increments = changeset.increments().to_vec();
-        if !versions.is_empty() && increments.iter().all(Option::is_none) {
+        if !versions.is_empty() && !changeset.is_releasable() {
             eprintln!("Nothing to release");
             return Ok(());
         }
 
-        // Ensure working directory is clean
-        if !context.repository.is_clean()? {
-            eprintln!("Working directory contains changes");
-            return Ok(());
+        // Use the initial version only if no versions exist yet, since it's
+        // meant for bootstrapping the first release of a project
+        let initial = self.initial.as_ref().filter(|_| versions.is_empty());
+        if self.initial.is_some() && initial.is_none() {
+            print_warning("Skipping initial version, as versions exist");
         }
 
-        // Ensure we're on the default branch
-        if !context.repository.on_default_branch()? {
-            eprintln!("Not on default branch");
+        // Ensure all preconditions are met, or abort otherwise
+        let forge = context.repository.forge().ok().flatten();
+        if !self.check(&context, forge.as_ref())? {
             return Ok(());
         }
 
         // Prompt the user whether a new version should be created
-        if !confirm("Create new version?")
-            .initial_value(true)
-            .interact()?
+        if flags.interactive
+            && !confirm("Create new version?")
+                .initial_value(true)
+                .interact()?
         {
             return Ok(());
         }
 
-        // Traverse dependents in topological order, to let the user review
-        // version increment suggestions in lock-step for choosing
+        // Select version increments for all packages, or bump all packages to
+        // the initial version, if this is the first release
         let dependents = context.workspace.dependents()?;
-        dependents.bump(&mut increments, |suggestion| {
-            let project = suggestion.project();
-            let increments = suggestion.increments();
-
-            // Retrieve namd and version of project - only packages are allowed
-            // to be dependents, which means name and version definitely exist
-            let name = project.name().expect("invariant");
-            let version = project.version().expect("invariant");
-
-            // Create select builder, and add all possible version increments,
-            // as depending on the changes, multiple increments are possible
-            let mut builder =
-                increments.iter().fold(select(name), |builder, &bump| {
-                    if let Some(next) = bump {
-                        builder.item(Some(next), version.bump(next), next)
-                    } else {
-                        builder.item(None, version, "current")
-                    }
-                });
-
-            // Prompt the user to select a version increment
-            Ok(builder.interact()?)
-        })?;
+        self.select_all(
+            &context,
+            &dependents,
+            &mut increments,
+            changeset.max_increment(),
+            initial.is_some(),
+            flags,
+        )?;
 
         // Denote completion of prompt to the user
-        outro(style("Versions selected").dim())?;
+        if flags.interactive {
+            outro(style("Versions selected").dim())?;
+        }
 
         // Determine sink - @todo make sure there is only one?
         let Some(sink) = dependents.sinks().next() else {
@@ -133,35 +616,432 @@ where
             return Ok(());
         };
 
-        // Extract version of sink
-        let version = dependents[sink].version().expect("invariant");
-        let version = if let Some(b) = increments[sink] {
-            version.bump(b)
-        } else {
-            version.clone()
-        };
+        // Extract version of sink, or use the version all packages are set to
+        let pre_release = self.pre_release.as_deref();
+        let target =
+            target(&context, &dependents, &increments, initial, pre_release)?;
+        let version = package_version(
+            &dependents,
+            sink,
+            &increments,
+            target.as_ref(),
+            pre_release,
+        )?;
+
+        // Derive tag name for version from tag format
+        let tag = format.tag(&version);
 
-        // Create a branch and bump all related files
-        context.repository.branch(format!("release/v{version}"))?;
-        context.workspace.bump(&increments)?;
+        // Print planned actions and abort, if this is a dry run
+        if self.dry_run {
+            let target = target.as_ref();
+            return self.print_plan(&dependents, &increments, target, &tag);
+        }
+
+        // Determine the branch to commit to, and ensure the release branch
+        // and tag don't exist yet
+        let branch = release_branch(&context.repository, &tag, flags)?;
 
-        // Create commit message with summary and body
-        let summary = prompt_commit_message(self.visual)?;
-        let message = format!("chore: release v{version}\n\n{summary}");
+        // Create commit message with summary and body, before changing the
+        // repository, so aborting the prompt leaves everything untouched
+        let order = &context.config.changelog.section_order;
+        let changelog = changeset.to_changelog().with_order(order);
+        let summary = self.release_notes(&changelog)?;
+        let message = format!("chore: release {tag}\n\n{summary}");
+
+        // Create a branch and bump all related files, rolling back all changes
+        // to the repository if any of the following steps fail
+        let mut guard = ReleaseGuard::new(&context.repository, &branch)?;
+        if flags.branch {
+            guard.branch()?;
+        }
+        let previous = to_owned_versions(context.workspace.versions_map());
+        let target = target.as_ref();
+        let bumped = self.bump(context.workspace, &increments, target)?;
 
         // Add all files and commit
         context.repository.add("*")?;
-        context.repository.commit(message)?;
+        let signoff = context.config.sign_off && !self.no_signoff;
+        context.repository.commit(message, self.sign, signoff)?;
+
+        // Create annotated tag for version, if desired, including the release
+        // notes, so they are shown by `git show` for the tag
+        if flags.tag {
+            let message = format!("Release {tag}\n\n{summary}");
+            context
+                .repository
+                .tag_create(&tag, Some(message), self.sign)?;
+        }
+
+        // Release was created successfully, so we keep all changes, and print
+        // the new versions of all packages that were bumped
+        guard.disarm();
+        for (name, version) in &bumped {
+            eprintln!("{} {name} {}", style("✔").green(), style(version).dim());
+        }
+
+        // Push branch and tag, if desired
+        if flags.push {
+            context.repository.push(&branch)?;
+            if flags.tag {
+                context.repository.push(&tag)?;
+            }
+        }
+
+        // Create GitHub release, if desired, with the release notes and the
+        // changelog, which links commits and issues to the repository
+        if let Some(forge) = forge.filter(|_| self.github_release) {
+            self.publish(&changelog, &forge, &tag, &version, &summary)?;
+        }
+
+        // Print summary of the release, if in batch mode
+        if self.batch {
+            print_summary(&branch, &tag, &previous, &bumped)?;
+        }
 
         // No errors occurred
         Ok(())
     }
 }
 
+// ----------------------------------------------------------------------------
+
+impl From<InitialIncrement> for Increment {
+    /// Creates a version increment from an initial increment.
+    #[inline]
+    fn from(increment: InitialIncrement) -> Self {
+        match increment {
+            InitialIncrement::Major => Increment::Major,
+            InitialIncrement::Minor => Increment::Minor,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Drop for ReleaseGuard<'_> {
+    /// Rolls back the release, unless the guard was disarmed.
+    ///
+    /// If the working directory contained changes before, we don't roll back
+    /// automatically, as this would discard them, but print instructions.
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        // Roll back, if we can do so without discarding the user's changes
+        if self.clean && self.rollback().is_ok() {
+            eprintln!("Release failed, rolled back to {}", self.head.short());
+            return;
+        }
+
+        // Print instructions for manual recovery, including the deletion of
+        // the release branch, but only if it was created by the guard
+        let target = self.branch.clone().unwrap_or(self.head.to_string());
+        let delete = if self.created {
+            format!("  git branch -D {}\n", self.release)
+        } else {
+            String::new()
+        };
+        eprintln!(
+            "{} Release failed, and repository could not be restored. To \
+             recover, run:\n\n  git checkout {target}\n{delete}",
+            style("Warning:").yellow().bold(),
+        );
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Functions
 // ----------------------------------------------------------------------------
 
+/// Prints a warning to standard error.
+fn print_warning(message: &str) {
+    eprintln!("{} {}", style("Warning:").yellow().bold(), message);
+}
+
+/// Returns the names of all packages that are excluded from the release,
+/// given as arguments or in the configuration.
+pub(super) fn excluded<'a>(
+    names: &'a [String], config: &'a Config,
+) -> BTreeSet<&'a str> {
+    let iter = names.iter().chain(&config.version.exclude);
+    iter.map(String::as_str).collect()
+}
+
+/// Determines the suggested version increments for all packages, without
+/// prompting, after applying the given increment to all packages, if they're
+/// bumped in lockstep, and skipping excluded packages, which are never bumped.
+pub(super) fn suggest<T>(
+    context: &Context<T>, dependents: &Dependents<T>,
+    increments: &mut [Option<Increment>], uniform: Option<Increment>,
+    excluded: &BTreeSet<&str>,
+) -> Result<Vec<Option<Increment>>>
+where
+    T: Manifest,
+{
+    if is_lockstep(context) {
+        let count = context.workspace.packages_count();
+        increments[..count].fill(uniform);
+    }
+
+    // Skip excluded packages, and select the highest suggested increments
+    exclude(dependents, increments, excluded);
+    let mut suggested = increments.to_vec();
+    select_increments(dependents, &mut suggested, false, excluded)?;
+    Ok(suggested)
+}
+
+/// Returns the version all bumped packages are set to, if any.
+///
+/// For the first release, this is the initial version. If packages are
+/// bumped in lockstep, this is the highest current version of all bumped
+/// packages after applying the selected increment, so packages that had
+/// different versions before share the same version afterwards.
+pub(super) fn target<T>(
+    context: &Context<T>, dependents: &Dependents<T>,
+    increments: &[Option<Increment>], initial: Option<&Version>,
+    pre_release: Option<&str>,
+) -> Result<Option<Version>>
+where
+    T: Manifest,
+{
+    if initial.is_some() || !is_lockstep(context) {
+        return Ok(initial.cloned());
+    }
+
+    // Determine the highest current version of all bumped packages, and
+    // apply the increment, which is the same for all packages
+    let iter = dependents.into_iter().filter_map(|node| {
+        let increment = increments[node]?;
+        Some((dependents[node].version()?, increment))
+    });
+    let Some((version, increment)) = iter.max() else {
+        return Ok(None);
+    };
+
+    // Warn the user if packages had different versions before
+    let target = next_version(version, increment, pre_release)?;
+    if !context.workspace.is_uniform_version() {
+        print_warning(&format!(
+            "Packages have different versions, all will be set to {target}"
+        ));
+    }
+
+    // Return target version
+    Ok(Some(target))
+}
+
+/// Returns the version of the given package after applying its increment, or
+/// the target version, if all bumped packages are set to it.
+pub(super) fn package_version<T>(
+    dependents: &Dependents<T>, node: usize, increments: &[Option<Increment>],
+    target: Option<&Version>, pre_release: Option<&str>,
+) -> Result<Version>
+where
+    T: Manifest,
+{
+    let version = dependents[node].version().expect("invariant");
+    match (target, increments[node]) {
+        (Some(target), _) => Ok(target.clone()),
+        (None, Some(increment)) => {
+            next_version(version, increment, pre_release)
+        }
+        (None, None) => Ok(version.clone()),
+    }
+}
+
+/// Returns the next version after applying the given increment, which is
+/// a pre-release version, if an identifier was given.
+pub(super) fn next_version(
+    version: &Version, increment: Increment, pre_release: Option<&str>,
+) -> Result<Version> {
+    match pre_release {
+        Some(identifier) => Ok(version.bump_pre(increment, identifier)?),
+        None => Ok(version.bump(increment)),
+    }
+}
+
+/// Returns whether packages are bumped in lockstep, which is also the case if
+/// there's only a single package in the workspace.
+fn is_lockstep<T>(context: &Context<T>) -> bool
+where
+    T: Manifest,
+{
+    context.config.strategy == Strategy::Lockstep
+        || context.workspace.is_single_package()
+}
+
+/// Removes the version increments of all excluded packages, and warns about
+/// packages depending on them, as their changes won't propagate.
+fn exclude<T>(
+    dependents: &Dependents<T>, increments: &mut [Option<Increment>],
+    excluded: &BTreeSet<&str>,
+) where
+    T: Manifest,
+{
+    for node in dependents {
+        let project = dependents[node];
+        let name = project.name().expect("invariant");
+        if excluded.contains(name) {
+            increments[node] = None;
+            continue;
+        }
+
+        // Warn about dependencies on excluded packages
+        for dependency in project.dependencies() {
+            if excluded.contains(dependency) {
+                print_warning(&format!(
+                    "{name} depends on excluded package {dependency}, whose \
+                     changes won't propagate"
+                ));
+            }
+        }
+    }
+}
+
+/// Prompts the user to select a single increment for all packages.
+///
+/// If prompts are disabled, the highest increment is returned.
+fn select_uniform(
+    max: Option<Increment>, flags: Flags,
+) -> Result<Option<Increment>> {
+    if !flags.interactive {
+        return Ok(max);
+    }
+
+    // Create select builder, and add all version increments
+    let builder = select("Which increment for all packages?");
+    let mut builder = builder.item(None, "current", "");
+    for increment in [Increment::Patch, Increment::Minor, Increment::Major] {
+        builder = builder.item(Some(increment), increment, "");
+    }
+
+    // Prompt the user to select a version increment
+    Ok(builder.initial_value(max).interact()?)
+}
+
+/// Prompts the user to select version increments for all packages.
+///
+/// If prompts are disabled, the highest suggested version increment is
+/// selected for each package. Excluded packages are never bumped.
+fn select_increments<T>(
+    dependents: &Dependents<T>, increments: &mut [Option<Increment>],
+    prompt: bool, excluded: &BTreeSet<&str>,
+) -> Result
+where
+    T: Manifest,
+{
+    dependents.bump(increments, |suggestion| {
+        let project = suggestion.project();
+        let increments = suggestion.increments();
+
+        // Skip excluded packages, even if dependencies were bumped
+        if project.name().is_some_and(|name| excluded.contains(name)) {
+            return Ok(None);
+        }
+
+        // Select the highest version increment if prompts are disabled -
+        // suggestions are ordered, so the last one is always the highest
+        if !prompt {
+            return Ok(increments.last().copied().flatten());
+        }
+
+        // Retrieve namd and version of project - only packages are allowed
+        // to be dependents, which means name and version definitely exist
+        let name = project.name().expect("invariant");
+        let version = project.version().expect("invariant");
+
+        // Create select builder, and add all possible version increments,
+        // as depending on the changes, multiple increments are possible
+        let mut builder =
+            increments.iter().fold(select(name), |builder, &bump| {
+                if let Some(next) = bump {
+                    builder.item(Some(next), version.bump(next), next)
+                } else {
+                    builder.item(None, version, "current")
+                }
+            });
+
+        // Prompt the user to select a version increment
+        Ok(builder.interact()?)
+    })?;
+
+    // No errors occurred
+    Ok(())
+}
+
+/// Ensures the given branch doesn't exist yet.
+fn ensure_branch_absent(repository: &Repository, branch: &str) -> Result {
+    for res in repository.branches()? {
+        if res? == branch {
+            return Err(Error::BranchExists(branch.to_string()));
+        }
+    }
+
+    // No errors occurred
+    Ok(())
+}
+
+/// Returns the branch to commit to, which is the release branch, unless
+/// disabled, in which case it's the current branch.
+///
+/// This ensures that the release branch and tag don't exist yet, as their
+/// creation would otherwise fail after the user entered the release notes.
+fn release_branch(
+    repository: &Repository, tag: &str, flags: Flags,
+) -> Result<String> {
+    let branch = if flags.branch {
+        let branch = format!("release/{tag}");
+        ensure_branch_absent(repository, &branch)?;
+        branch
+    } else {
+        repository.current_branch()?.ok_or(Error::Detached)?
+    };
+
+    // Ensure tag doesn't exist yet, if desired
+    if flags.tag && repository.tag_exists(tag) {
+        return Err(Error::TagExists(tag.to_string()));
+    }
+
+    // Return branch
+    Ok(branch)
+}
+
+/// Converts a map of package names and versions into owned values, so they
+/// outlive modifications of the workspace.
+fn to_owned_versions(
+    versions: BTreeMap<&str, &Version>,
+) -> BTreeMap<String, Version> {
+    let iter = versions.into_iter();
+    iter.map(|(name, version)| (name.to_string(), version.clone()))
+        .collect()
+}
+
+/// Prints a summary of the release as JSON to standard out, including the
+/// previous and new versions of all packages that were bumped.
+fn print_summary(
+    branch: &str, tag: &str, previous: &BTreeMap<String, Version>,
+    bumped: &BTreeMap<String, Version>,
+) -> Result {
+    let iter = bumped.iter().map(|(name, version)| Package {
+        name,
+        from: previous.get(name),
+        to: version,
+    });
+
+    // Write summary to standard out
+    let summary = Summary {
+        branch,
+        tag,
+        packages: iter.collect(),
+    };
+    serde_json::to_writer(io::stdout(), &summary)?;
+    println!();
+
+    // No errors occurred
+    Ok(())
+}
+
 /// Prompts the user to enter a commit message.
 fn prompt_commit_message(visual: bool) -> Result<String> {
     let mut temp = NamedTempFile::new()?;
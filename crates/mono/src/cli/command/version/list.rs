@@ -26,11 +26,15 @@
 //! List versions in reverse chronological order.
 
 use clap::Args;
+use semver::Version;
+use serde::Serialize;
 use std::fmt::Debug;
+use std::io;
 
+use mono_project::version::VersionExt;
 use mono_project::Manifest;
 
-use crate::cli::{Command, Result};
+use crate::cli::{Command, Format, Result};
 use crate::Context;
 
 // ----------------------------------------------------------------------------
@@ -41,8 +45,33 @@ use crate::Context;
 #[derive(Args, Debug)]
 pub struct Arguments {
     /// Show only the latest version.
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "limit")]
     latest: bool,
+    /// Show only the given number of most recent versions.
+    #[arg(long)]
+    limit: Option<usize>,
+    /// Show only versions newer than the given version.
+    #[arg(long, value_parser = Version::from_str_with_prefix)]
+    since: Option<Version>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// Fetch tags from the configured remote before listing versions.
+    #[arg(long)]
+    fetch: bool,
+}
+
+/// Version.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    /// Version.
+    version: &'a Version,
+    /// Tag name.
+    tag: String,
+    /// Commit identifier.
+    commit: String,
+    /// Short commit identifier.
+    short: String,
 }
 
 // ----------------------------------------------------------------------------
@@ -55,16 +84,44 @@ where
 {
     /// Executes the command.
     fn execute(&self, context: Context<T>) -> Result {
-        // Resolve and list all versions, and abort after writing the latest
-        // version to standard out if only the latest version is requested
-        let versions = context.repository.versions()?;
-        for (version, _) in &versions {
-            println!("v{version}");
-            if self.latest {
-                break;
+        // Fetch tags from remote, if desired, as local tags might be stale
+        if self.fetch {
+            context.repository.fetch(&context.config.remote)?;
+        }
+
+        // Resolve versions, and limit them to the latest version, if only the
+        // latest version is requested, or to the given number and range
+        let format = context.config.version.to_format(&context.workspace)?;
+        let versions = context.repository.versions_with_format(&format)?;
+        let limit = if self.latest { Some(1) } else { self.limit };
+        let iter = versions
+            .iter()
+            .filter(|(version, _)| {
+                self.since.as_ref().is_none_or(|since| *version > since)
+            })
+            .take(limit.unwrap_or(usize::MAX));
+
+        // List versions in reverse chronological order
+        if self.format == Format::Plain {
+            for (version, _) in iter {
+                println!("{}", format.tag(version));
             }
+            return Ok(());
         }
 
+        // Collect versions with tags and commits, and write to standard out
+        let mut output = Vec::with_capacity(
+            limit.map_or(versions.len(), |limit| limit.min(versions.len())),
+        );
+        output.extend(iter.map(|(version, id)| Output {
+            version,
+            tag: format.tag(version),
+            commit: id.to_string(),
+            short: id.short(),
+        }));
+        serde_json::to_writer(io::stdout(), &output)?;
+        println!();
+
         // No errors occurred
         Ok(())
     }
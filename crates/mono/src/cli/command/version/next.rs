@@ -0,0 +1,139 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! Print the next version without creating it.
+
+use clap::Args;
+use serde::Serialize;
+use std::process;
+
+use mono_changeset::Changeset;
+use mono_project::Manifest;
+
+use crate::cli::{Command, Format, Result};
+use crate::Context;
+
+use super::create::{excluded, package_version, suggest, target};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// Print the next version without creating it.
+#[derive(Args, Debug)]
+pub struct Arguments {
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// Print pre-release with identifier, e.g., `rc` for `2.0.0-rc.1`.
+    #[arg(long, value_name = "IDENTIFIER")]
+    pre_release: Option<String>,
+    /// Exclude the given package from the release (repeatable).
+    #[arg(long, value_name = "NAME")]
+    exclude: Vec<String>,
+}
+
+/// Next version.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    /// Package name.
+    name: &'a str,
+    /// Package version.
+    version: String,
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Command<T> for Arguments
+where
+    T: Manifest,
+{
+    /// Executes the command.
+    fn execute(&self, context: Context<T>) -> Result {
+        // Ensure history is complete, or warn the user otherwise
+        context.ensure_complete_history()?;
+
+        // Resolve versions and create changeset, then determine all commits
+        // that were added after the latest version was released
+        let tag_format =
+            context.config.version.to_format(&context.workspace)?;
+        let versions = context.repository.versions_with_format(&tag_format)?;
+        let mut changeset = Changeset::new(&context.workspace)?;
+        for res in versions.commits(None)? {
+            changeset.add(res?)?;
+        }
+
+        // Obtain version increments, which denote which packages have changed,
+        // and exit with an error if there are no changes that require a
+        // release, even if no versions exist yet, as the next version would be
+        // the current version
+        let mut increments = changeset.increments().to_vec();
+        if !changeset.is_releasable() {
+            process::exit(1);
+        }
+
+        // Select the highest version increment suggestion for each package
+        // automatically, exactly as done when creating a version
+        let dependents = context.workspace.dependents()?;
+        let excluded = excluded(&self.exclude, &context.config);
+        let max = changeset.max_increment();
+        let increments =
+            suggest(&context, &dependents, &mut increments, max, &excluded)?;
+
+        // Determine sink, which is the canonical package of the workspace
+        let Some(sink) = dependents.sinks().next() else {
+            process::exit(1);
+        };
+
+        // Extract name and version of sink, or use the version all packages
+        // are set to, if they're bumped in lockstep
+        let pre_release = self.pre_release.as_deref();
+        let target =
+            target(&context, &dependents, &increments, None, pre_release)?;
+        let name = dependents[sink].name().expect("invariant");
+        let version = package_version(
+            &dependents,
+            sink,
+            &increments,
+            target.as_ref(),
+            pre_release,
+        )?;
+
+        // Write next version to standard out
+        match self.format {
+            Format::Plain => println!("{version}"),
+            Format::Json => {
+                let version = version.to_string();
+                let output = Output { name, version };
+                println!("{}", serde_json::to_string(&output)?);
+            }
+        }
+
+        // No errors occurred
+        Ok(())
+    }
+}
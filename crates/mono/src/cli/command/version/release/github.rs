@@ -0,0 +1,151 @@
+// Copyright (c) 2025 Zensical and contributors
+
+// SPDX-License-Identifier: MIT
+// Third-party contributions licensed under DCO
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+// ----------------------------------------------------------------------------
+
+//! GitHub Releases.
+
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::{env, str};
+use tempfile::NamedTempFile;
+
+use mono_repository::Forge;
+
+use crate::cli::{Error, Result};
+
+// ----------------------------------------------------------------------------
+// Structs
+// ----------------------------------------------------------------------------
+
+/// GitHub release.
+///
+/// Releases are created through the GitHub REST API. Since we don't want to
+/// pull in an HTTP client for a single request, we fall back to `curl`, like
+/// we fall back to `git` for operations that `libgit2` doesn't support well.
+#[derive(Debug, Serialize)]
+pub struct Release<'a> {
+    /// Tag name.
+    tag_name: &'a str,
+    /// Release name.
+    name: &'a str,
+    /// Release notes in Markdown format.
+    body: &'a str,
+    /// Whether the release is a pre-release.
+    prerelease: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<'a> Release<'a> {
+    /// Creates a GitHub release for the given tag.
+    pub fn new(tag: &'a str, body: &'a str, prerelease: bool) -> Self {
+        Self {
+            tag_name: tag,
+            name: tag,
+            body,
+            prerelease,
+        }
+    }
+
+    /// Publishes the release to the repository of the given forge.
+    ///
+    /// The token is read from the `GITHUB_TOKEN` environment variable, and is
+    /// passed to `curl` through standard input, so it doesn't show up in the
+    /// list of processes. The API of GitHub Enterprise is supported as well.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::GitHubToken`] if the token is not set,
+    /// [`Error::GitHubRemote`] if the forge is not GitHub, and
+    /// [`Error::GitHubRelease`] if the request fails.
+    pub fn publish(&self, forge: &Forge) -> Result {
+        let token = token()?;
+        let Forge::GitHub { host, path } = forge else {
+            return Err(Error::GitHubRemote);
+        };
+
+        // Determine API endpoint, which differs for GitHub Enterprise
+        let url = if host == "github.com" {
+            format!("https://api.github.com/repos/{path}/releases")
+        } else {
+            format!("https://{host}/api/v3/repos/{path}/releases")
+        };
+
+        // Write payload to a temporary file, as standard input is used for
+        // passing the configuration including the authorization header
+        let mut file = NamedTempFile::new()?;
+        serde_json::to_writer(&mut file, self)?;
+        let config = format!(
+            "header = \"Authorization: Bearer {token}\"\n\
+             header = \"Accept: application/vnd.github+json\"\n"
+        );
+
+        // Send request, failing on HTTP errors but keeping the response body
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail-with-body"])
+            .args(["--config", "-", "--data-binary"])
+            .arg(format!("@{}", file.path().display()))
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(config.as_bytes())?;
+        }
+
+        // Return error with response, if unsuccessful
+        let output = child.wait_with_output()?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = format!("{} {}", stderr.trim(), stdout.trim());
+            Err(Error::GitHubRelease(message.trim().to_string()))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the GitHub token from the environment.
+///
+/// This function can be used to ensure the token is set before creating a
+/// release, so we don't leave the user with a half-finished release.
+///
+/// # Errors
+///
+/// This function returns [`Error::GitHubToken`] if the token is not set.
+pub fn token() -> Result<String> {
+    env::var("GITHUB_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+        .ok_or(Error::GitHubToken)
+}
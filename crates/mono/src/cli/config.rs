@@ -28,15 +28,168 @@
 use serde::Deserialize;
 
 use mono_changeset as changeset;
+use mono_changeset::change::Kind;
+use mono_changeset::changelog::Category;
+use mono_project::{Manifest, Workspace};
+use mono_repository::versions::Format;
+
+use super::{Error, Result};
 
 // ----------------------------------------------------------------------------
 // Structs
 // ----------------------------------------------------------------------------
 
 /// Configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Deserialize)]
 pub struct Config {
     /// Changeset.
     #[serde(default)]
     pub changeset: changeset::Config,
+    /// Changelog configuration.
+    #[serde(default)]
+    pub changelog: ChangelogConfig,
+    /// Version configuration.
+    #[serde(default)]
+    pub version: VersionConfig,
+    /// Branches that releases can be created from.
+    #[serde(default = "default_branches")]
+    pub default_branches: Vec<String>,
+    /// Remote to fetch tags from, defaults to `origin`.
+    #[serde(default = "default_remote")]
+    pub remote: String,
+    /// Versioning strategy.
+    #[serde(default)]
+    pub strategy: Strategy,
+    /// Add `Signed-off-by` trailer to release commits, as required by the DCO,
+    /// which can be disabled with `sign_off = false`.
+    #[serde(default = "default_sign_off")]
+    pub sign_off: bool,
+    /// Validation configuration.
+    #[serde(default)]
+    pub validate: ValidateConfig,
+}
+
+/// Changelog configuration.
+#[derive(Debug, Default, Deserialize)]
+pub struct ChangelogConfig {
+    /// Order of sections, defaults to the order of categories.
+    #[serde(default)]
+    pub section_order: Vec<Category>,
+}
+
+/// Version configuration.
+#[derive(Debug, Default, Deserialize)]
+pub struct VersionConfig {
+    /// Tag prefix, defaults to `v`.
+    pub tag_prefix: Option<String>,
+    /// Tag format, defaults to `{prefix}{version}`.
+    pub tag_format: Option<String>,
+    /// Packages that are never released, e.g., internal tooling.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Validation configuration.
+#[derive(Debug, Default, Deserialize)]
+pub struct ValidateConfig {
+    /// Change kinds that are allowed, defaults to all.
+    #[serde(default)]
+    pub allowed_kinds: Vec<Kind>,
+    /// Change kinds that are denied.
+    #[serde(default)]
+    pub denied_kinds: Vec<Kind>,
+}
+
+// ----------------------------------------------------------------------------
+// Enums
+// ----------------------------------------------------------------------------
+
+/// Versioning strategy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strategy {
+    /// Packages are versioned independently.
+    #[default]
+    Independent,
+    /// Packages are always bumped together to the same version.
+    Lockstep,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl VersionConfig {
+    /// Returns the tag format for the given workspace.
+    ///
+    /// The `{prefix}` placeholder is replaced with the tag prefix, and `{name}`
+    /// with the name of the canonical package of the workspace, which is the
+    /// package that all other packages eventually lead to.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Placeholder`], if the template contains a
+    /// `{name}` placeholder, but the workspace has no canonical package.
+    pub fn to_format<T>(&self, workspace: &Workspace<T>) -> Result<Format>
+    where
+        T: Manifest,
+    {
+        let prefix = self.tag_prefix.as_deref().unwrap_or("v");
+        let format = self.tag_format.as_deref().unwrap_or("{prefix}{version}");
+        let mut format = format.replace("{prefix}", prefix);
+
+        // Only resolve dependents if the name of the sink is required
+        if format.contains("{name}") {
+            let dependents = workspace.dependents()?;
+            let Some(sink) = dependents.sinks().next() else {
+                return Err(Error::Placeholder(String::from("{name}")));
+            };
+
+            // Replace placeholder with name of canonical package
+            let name = dependents[sink].name().expect("invariant");
+            format = format.replace("{name}", name);
+        }
+
+        // Parse and return tag format
+        Ok(format.parse()?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Trait implementations
+// ----------------------------------------------------------------------------
+
+impl Default for Config {
+    /// Creates a default configuration.
+    fn default() -> Self {
+        Self {
+            changeset: changeset::Config::default(),
+            changelog: ChangelogConfig::default(),
+            version: VersionConfig::default(),
+            default_branches: default_branches(),
+            remote: default_remote(),
+            strategy: Strategy::default(),
+            sign_off: default_sign_off(),
+            validate: ValidateConfig::default(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Functions
+// ----------------------------------------------------------------------------
+
+/// Returns the default branches, i.e., `main` and `master`.
+fn default_branches() -> Vec<String> {
+    vec![String::from("main"), String::from("master")]
+}
+
+/// Returns the default remote, i.e., `origin`.
+fn default_remote() -> String {
+    String::from("origin")
+}
+
+/// Returns whether to add a `Signed-off-by` trailer by default, i.e., `true`.
+fn default_sign_off() -> bool {
+    true
 }
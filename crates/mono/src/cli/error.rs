@@ -29,6 +29,7 @@ use std::{io, result};
 use thiserror::Error;
 
 use mono_changeset as changeset;
+use mono_changeset::changelog::file;
 use mono_project as project;
 use mono_repository as repository;
 
@@ -45,18 +46,53 @@ pub enum Error {
     /// TOML error.
     #[error(transparent)]
     Toml(#[from] toml::de::Error),
+    /// JSON error.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
     /// Version error.
     #[error(transparent)]
     Version(#[from] semver::Error),
     /// Changeset error.
     #[error(transparent)]
     Changeset(#[from] changeset::Error),
+    /// Changelog file error.
+    #[error(transparent)]
+    File(#[from] file::Error),
     /// Project error.
     #[error(transparent)]
     Project(#[from] project::Error),
     /// Repository error.
     #[error(transparent)]
     Repository(#[from] repository::Error),
+    /// Tag format placeholder can't be resolved.
+    #[error("tag format placeholder `{0}` can't be resolved")]
+    Placeholder(String),
+    /// Signing not configured.
+    #[error(
+        "signing requires `user.signingKey` or `gpg.program` in git config"
+    )]
+    Signing,
+    /// Branch already exists.
+    #[error("branch `{0}` already exists")]
+    BranchExists(String),
+    /// No branch checked out.
+    #[error("`--no-branch` requires a branch to be checked out")]
+    Detached,
+    /// Tag already exists.
+    #[error("tag `{0}` already exists")]
+    TagExists(String),
+    /// GitHub token not set.
+    #[error("GitHub release requires `GITHUB_TOKEN` to be set")]
+    GitHubToken,
+    /// Remote is not hosted on GitHub.
+    #[error("GitHub release requires `origin` to be a GitHub repository")]
+    GitHubRemote,
+    /// GitHub release could not be created.
+    #[error("GitHub release could not be created: {0}")]
+    GitHubRelease(String),
+    /// Shallow clone.
+    #[error("repository is a shallow clone, run `git fetch --unshallow`")]
+    Shallow,
 }
 
 // ----------------------------------------------------------------------------
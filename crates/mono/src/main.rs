@@ -28,12 +28,12 @@
 use clap::Parser;
 use std::fs;
 
-use mono_project::{Cargo, Manifest, Node, Workspace};
+use mono_project::{Cargo, Manifest, Node, Python, Workspace};
 use mono_repository::Repository;
 
 mod cli;
 
-use cli::{Cli, Config, Result};
+use cli::{Cli, Config, Error, Result};
 
 // ----------------------------------------------------------------------------
 // Structs
@@ -51,6 +51,38 @@ where
     workspace: Workspace<T>,
     /// Configuration.
     config: Config,
+    /// Fail on shallow clones.
+    fail_on_shallow: bool,
+}
+
+// ----------------------------------------------------------------------------
+// Implementations
+// ----------------------------------------------------------------------------
+
+impl<T> Context<T>
+where
+    T: Manifest,
+{
+    /// Ensures the repository is not a shallow clone.
+    ///
+    /// Commands that traverse commits would silently miss history, so we warn
+    /// the user, or fail, if we should not continue with a shallow clone.
+    fn ensure_complete_history(&self) -> Result {
+        if !self.repository.is_shallow() {
+            return Ok(());
+        }
+
+        // Fail or warn, pointing the user to the remedy
+        if self.fail_on_shallow {
+            Err(Error::Shallow)
+        } else {
+            eprintln!(
+                "Warning: repository is a shallow clone, so history might be \
+                 incomplete - run `git fetch --unshallow` to fetch it"
+            );
+            Ok(())
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -72,11 +104,13 @@ fn main() -> Result {
         Config::default()
     };
 
-    // Initialize cargo or node workspace
+    // Initialize cargo, node or python workspace
     if let Ok(workspace) = Workspace::<Cargo>::resolve(path) {
         cli.execute(repository, workspace, config);
     } else if let Ok(workspace) = Workspace::<Node>::resolve(path) {
         cli.execute(repository, workspace, config);
+    } else if let Ok(workspace) = Workspace::<Python>::resolve(path) {
+        cli.execute(repository, workspace, config);
     }
 
     // No errors occurred